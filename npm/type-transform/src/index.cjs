@@ -10,6 +10,7 @@ const { arch: getArch, platform: getPlatform } = require('node:os');
  * @param {Object} [options={}] - Optional parameters for transformation.
  * @param {string} [options.banner] - An optional banner string to be added to the output.
  * @param {string} [options.footer] - An optional footer string to be added to the output.
+ * @param {string} [options.config] - An optional path to a JSON config file with target specific options.
  * @returns {Promise<{ success: boolean }>} - A promise that resolves with an object indicating success or failure.
  */
 function transform(srcFilePath, outFilePath, options = {}) {
@@ -25,6 +26,10 @@ function transform(srcFilePath, outFilePath, options = {}) {
       args.push(`--footer ${options.footer}`);
     }
 
+    if (typeof options?.config === 'string') {
+      args.push(`--config ${options.config}`);
+    }
+
     let cmd = '';
     try {
       cmd = `${fileURLToPath(exePath)} ${args.join(' ')}`;
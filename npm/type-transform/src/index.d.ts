@@ -10,6 +10,11 @@ export declare interface TransformOptions {
    * or just a fun easter egg
    */
   readonly footer?: string;
+
+  /**
+   * Path to a JSON config file with target specific options, such as the Java class style
+   */
+  readonly config?: string;
 }
 
 export declare interface TransformResult {
@@ -0,0 +1 @@
+pub mod transform_config;
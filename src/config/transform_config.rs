@@ -0,0 +1,275 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::utils::naming_utils::{
+  to_camel_case, to_pascal_case, to_screaming_snake_case, to_snake_case,
+};
+
+/// Options read from the `--config` JSON file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TransformConfig {
+  /// Swift and Kotlin emit `number` properties and params with a matching
+  /// name as integers, `*` matches any run of characters such as `*Id`
+  pub integer_name_patterns: Vec<String>,
+  pub swift: SwiftConfig,
+  pub kotlin: KotlinConfig,
+  pub java: JavaConfig,
+  pub python: PythonConfig,
+  pub go: GoConfig,
+  pub proto: ProtoConfig,
+}
+
+/// Conventions of the generated names, a target keeps its own style for the
+/// kinds left out
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NamingConventions {
+  pub types: Option<NamingConvention>,
+  pub properties: Option<NamingConvention>,
+  pub methods: Option<NamingConvention>,
+  pub enum_cases: Option<NamingConvention>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NamingConvention {
+  Preserve,
+  CamelCase,
+  PascalCase,
+  SnakeCase,
+  ScreamingSnakeCase,
+}
+
+impl NamingConvention {
+  pub fn apply(self, name: &str) -> String {
+    match self {
+      NamingConvention::Preserve => name.to_string(),
+      NamingConvention::CamelCase => to_camel_case(name),
+      NamingConvention::PascalCase => to_pascal_case(&to_snake_case(name)),
+      NamingConvention::SnakeCase => to_snake_case(name),
+      NamingConvention::ScreamingSnakeCase => to_screaming_snake_case(name),
+    }
+  }
+}
+
+/// TS type name -> native type, such as `Decimal` -> `java.math.BigDecimal`
+pub type TypeMappings = BTreeMap<String, TypeMapping>;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeMapping {
+  /// Native type, generic arguments are appended unless it refers to them
+  /// with `$0`, `$1` ..
+  #[serde(rename = "type")]
+  pub native_type: String,
+  /// Modules or fully qualified names to import when the type is used
+  #[serde(default)]
+  pub imports: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SwiftConfig {
+  /// Type of `unknown` and `any`, `json` (`AnyCodable`) or `any`
+  pub unknown_type: UnknownType,
+  /// Adds an `unknown(rawValue)` case to enums so new values still decode
+  pub unknown_enum_case: bool,
+  /// Access level of the exported declarations and their members
+  pub access_level: SwiftAccessLevel,
+  /// Shape of the generated records, `struct`, `finalClass` or `observable`
+  pub record_style: SwiftRecordStyle,
+  /// How `Promise` results are awaited
+  pub async_style: SwiftAsyncStyle,
+  /// Emits `onX(listener)` subscription methods as `AsyncStream` properties
+  pub listener_streams: bool,
+  pub naming: NamingConventions,
+  pub type_mappings: TypeMappings,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct KotlinConfig {
+  /// Type of `bigint`, `long` or `bigInteger`
+  pub bigint_type: KotlinBigIntType,
+  /// Type of `unknown` and `any`, `json` (`JsonElement`) or `any`
+  pub unknown_type: UnknownType,
+  /// Adds an `UNKNOWN` entry to enums that values the enum does not declare decode to
+  pub unknown_enum_case: bool,
+  /// Visibility of every generated declaration
+  pub visibility: KotlinVisibility,
+  /// How `Promise` results are awaited
+  pub async_style: KotlinAsyncStyle,
+  /// Emits `onX(listener)` subscription methods as `Flow` properties
+  pub listener_streams: bool,
+  pub naming: NamingConventions,
+  pub type_mappings: TypeMappings,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SwiftAccessLevel {
+  #[default]
+  Public,
+  Package,
+  Internal,
+  /// `open` classes that can be subclassed outside the module, `public` for
+  /// structs, enums, protocols and members
+  Open,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SwiftRecordStyle {
+  #[default]
+  Struct,
+  FinalClass,
+  /// `@Observable` final class, for SwiftUI view models
+  Observable,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SwiftAsyncStyle {
+  #[default]
+  AsyncThrows,
+  /// Trailing `completion: @escaping (Result<T, Error>) -> Void` param
+  CompletionHandler,
+  /// `AnyPublisher<T, Error>`
+  Combine,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KotlinAsyncStyle {
+  /// `suspend` functions, `Deferred<T>` properties
+  #[default]
+  Suspend,
+  /// Trailing `callback: (Result<T>) -> Unit` param
+  Callback,
+  Deferred,
+  CompletableFuture,
+  /// RxJava `Single<T>`
+  RxSingle,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KotlinVisibility {
+  #[default]
+  Public,
+  Internal,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnknownType {
+  #[default]
+  Json,
+  Any,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KotlinBigIntType {
+  #[default]
+  Long,
+  BigInteger,
+}
+
+/// Java keeps the TS names, there is no `naming`. Names that are not Java
+/// identifiers are sanitized and keep their JSON key with `@JsonProperty`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JavaConfig {
+  /// Shape of the generated data types, `record` or `pojo`
+  pub class_style: JavaClassStyle,
+  pub type_mappings: TypeMappings,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JavaClassStyle {
+  #[default]
+  Record,
+  Pojo,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PythonConfig {
+  /// Base of the generated data types, `dataclass` or `pydantic`
+  pub model_style: PythonModelStyle,
+  /// Properties and methods are `snake_case` unless configured
+  pub naming: NamingConventions,
+  pub type_mappings: TypeMappings,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PythonModelStyle {
+  #[default]
+  Dataclass,
+  Pydantic,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GoConfig {
+  /// Name used in the `package` clause
+  pub package_name: String,
+  /// How optional properties are represented, `pointer` or `omitEmpty`
+  pub optional_fields: GoOptionalFields,
+  /// Properties, methods and enum constants are exported `PascalCase` unless configured
+  pub naming: NamingConventions,
+  pub type_mappings: TypeMappings,
+}
+
+impl Default for GoConfig {
+  fn default() -> Self {
+    Self {
+      package_name: "types".to_string(),
+      optional_fields: GoOptionalFields::default(),
+      naming: NamingConventions::default(),
+      type_mappings: TypeMappings::default(),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GoOptionalFields {
+  #[default]
+  Pointer,
+  OmitEmpty,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProtoConfig {
+  /// Optional `package` declaration
+  pub package: Option<String>,
+}
+
+impl TransformConfig {
+  /// Naming conventions configured for `target`, such as `swift`, the default
+  /// for `java` which has none
+  pub fn naming(&self, target: &str) -> NamingConventions {
+    match target {
+      "swift" => self.swift.naming,
+      "kotlin" => self.kotlin.naming,
+      "python" => self.python.naming,
+      "go" => self.go.naming,
+      _ => NamingConventions::default(),
+    }
+  }
+
+  pub fn from_file(file_name: &str) -> Result<Self, String> {
+    let config_text = fs::read_to_string(Path::new(file_name))
+      .map_err(|_| format!("Error: Unable to read config file: {file_name}"))?;
+
+    serde_json::from_str(&config_text)
+      .map_err(|err| format!("Error: Unable to parse config file: {file_name}, {err}"))
+  }
+}
@@ -0,0 +1,39 @@
+use oxc_ast::ast::TSEnumDeclaration;
+
+use crate::languages::shared::enum_values::{EnumValue, EnumValues, is_heterogeneous};
+
+pub trait GoEnumDisplayType {
+  fn to_go_enum_display_type(&self) -> String;
+}
+
+impl GoEnumDisplayType for TSEnumDeclaration<'_> {
+  fn to_go_enum_display_type(&self) -> String {
+    let values = self
+      .get_enum_values()
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>();
+
+    // constants have a single type, enums mixing strings and numbers hold `any`
+    if is_heterogeneous(&values) {
+      return "any".to_string();
+    }
+
+    let all_enum_string =
+      !values.is_empty() && values.iter().all(|x| matches!(x, EnumValue::String(_)));
+
+    if all_enum_string {
+      return "string".to_string();
+    }
+
+    let any_enum_float = values
+      .iter()
+      .any(|x| matches!(x, EnumValue::Number(value) if value.fract() != 0.0));
+
+    if any_enum_float {
+      return "float64".to_string();
+    }
+
+    "int".to_string()
+  }
+}
@@ -0,0 +1,26 @@
+use oxc_ast::ast::TSType;
+
+use crate::languages::shared::{is_async_trait::IsAsyncType, transform_context::TransformContext};
+
+use super::go_type_trait::GoType;
+
+pub trait GoFunctionReturnType {
+  fn to_go_fn_return_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl GoFunctionReturnType for TSType<'_> {
+  /// `Promise<T>` becomes `(T, error)`, the leading space is included when there is a result
+  fn to_go_fn_return_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSVoidKeyword(_) => "".to_string(),
+      TSType::TSTypeReference(val) if val.is_async_type() => {
+        let type_param = val.type_parameters.as_ref().and_then(|x| x.params.first());
+        match type_param {
+          Some(TSType::TSVoidKeyword(_)) | None => " error".to_string(),
+          Some(type_param) => format!(" ({}, error)", type_param.to_go_type(ctx)),
+        }
+      }
+      _ => format!(" {}", self.to_go_type(ctx)),
+    }
+  }
+}
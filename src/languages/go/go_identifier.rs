@@ -0,0 +1,53 @@
+use crate::{
+  config::transform_config::NamingConvention,
+  languages::{
+    go::go_type_trait::to_go_member_name,
+    shared::identifier::{suffix_reserved_word, to_valid_identifier},
+  },
+};
+
+/// Keywords that cannot be used as a name, predeclared names such as `string` can
+const GO_RESERVED_WORDS: &[&str] = &[
+  "break",
+  "case",
+  "chan",
+  "const",
+  "continue",
+  "default",
+  "defer",
+  "else",
+  "fallthrough",
+  "for",
+  "func",
+  "go",
+  "goto",
+  "if",
+  "import",
+  "interface",
+  "map",
+  "package",
+  "range",
+  "return",
+  "select",
+  "struct",
+  "switch",
+  "type",
+  "var",
+];
+
+/// A TS name as a Go identifier, sanitized and suffixed, `type` -> `type_`
+pub fn to_go_identifier(name: &str) -> String {
+  suffix_reserved_word(&to_valid_identifier(name), GO_RESERVED_WORDS)
+}
+
+/// A TS key or member name as a field or method name in the configured naming
+/// `convention`, `content-type` -> `ContentType`, `1st` -> `X1st`
+pub fn to_go_member_identifier(name: &str, convention: Option<NamingConvention>) -> String {
+  let member_name = to_go_member_name(&to_valid_identifier(name), convention);
+  match member_name.chars().next() {
+    // a leading digit is kept exported the way protoc-gen-go does
+    Some(first) if first.is_ascii_digit() => format!("X{member_name}"),
+    None => "X".to_string(),
+    Some(_) => suffix_reserved_word(&member_name, GO_RESERVED_WORDS),
+  }
+}
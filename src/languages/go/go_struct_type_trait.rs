@@ -0,0 +1,70 @@
+use oxc_ast::ast::{TSInterfaceDeclaration, TSSignature};
+
+use crate::{
+  config::transform_config::GoOptionalFields,
+  languages::{
+    go::{go_identifier::to_go_member_identifier, go_style, go_type_trait::GoType},
+    shared::transform_context::TransformContext,
+  },
+};
+
+pub trait GoStructType {
+  fn to_go_struct_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl GoStructType for TSInterfaceDeclaration<'_> {
+  fn to_go_struct_type(&self, ctx: &TransformContext) -> String {
+    let struct_name = ctx.declared_type_name(&self.id.name, "go");
+    let field_rows = self
+      .body
+      .body
+      .iter()
+      .filter_map(|signature| match signature {
+        TSSignature::TSPropertySignature(prop_sig) => {
+          let prop_name = prop_sig.key.to_go_type(ctx);
+          let type_annotation = prop_sig
+            .type_annotation
+            .as_ref()
+            .map(|annotation| annotation.type_annotation.to_go_type(ctx))
+            .unwrap_or_else(|| "any".to_string());
+
+          let (field_type, json_tag) = if prop_sig.optional {
+            (
+              to_go_optional_type(type_annotation, ctx.config.go.optional_fields),
+              format!("`json:\"{prop_name},omitempty\"`"),
+            )
+          } else {
+            (type_annotation, format!("`json:\"{prop_name}\"`"))
+          };
+
+          Some(vec![
+            format!(
+              "{}{}",
+              go_style::INDENT_SPACE,
+              to_go_member_identifier(&prop_name, ctx.config.go.naming.properties)
+            ),
+            field_type,
+            json_tag,
+          ])
+        }
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    let body_data = go_style::align_columns(&field_rows).join("\n");
+    format!("type {struct_name} struct {{\n{body_data}\n}}\n\n")
+  }
+}
+
+/// Types that already have a `nil` value are not wrapped in a pointer
+fn to_go_optional_type(type_name: String, optional_fields: GoOptionalFields) -> String {
+  let is_nillable = ["[]", "map[", "func(", "*"]
+    .iter()
+    .any(|prefix| type_name.starts_with(prefix))
+    || type_name == "any";
+
+  match optional_fields {
+    GoOptionalFields::Pointer if !is_nillable => format!("*{type_name}"),
+    _ => type_name,
+  }
+}
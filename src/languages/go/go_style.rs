@@ -0,0 +1,34 @@
+pub const INDENT_SPACE: &str = "\t";
+
+/// Pads every column but the last one to a common width, the way `gofmt` aligns
+/// struct fields and constant blocks
+pub fn align_columns(rows: &[Vec<String>]) -> Vec<String> {
+  let column_count = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+  let column_widths = (0..column_count)
+    .map(|index| {
+      rows
+        .iter()
+        .filter(|row| index + 1 < row.len())
+        .map(|row| row[index].chars().count())
+        .max()
+        .unwrap_or_default()
+    })
+    .collect::<Vec<_>>();
+
+  rows
+    .iter()
+    .map(|row| {
+      row
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+          if index + 1 < row.len() {
+            format!("{cell:<width$} ", width = column_widths[index])
+          } else {
+            cell.to_string()
+          }
+        })
+        .collect::<String>()
+    })
+    .collect()
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests {
+
+  use oxc_allocator::Allocator;
+  use oxc_parser::{ParseOptions, Parser};
+  use oxc_span::SourceType;
+
+  use crate::{
+    config::transform_config::TransformConfig,
+    languages::{go::go_transformer::GoTransformer, shared::transform_context::TransformContext},
+  };
+
+  fn transform(source_text: &str, config: &TransformConfig) -> String {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, source_text, source_type)
+      .with_options(ParseOptions {
+        ..ParseOptions::default()
+      })
+      .parse();
+
+    let program = parser_ret.program;
+    GoTransformer::transform(&program, &TransformContext::new(&program, config))
+  }
+
+  #[test]
+  fn it_converts_to_go_type() {
+    let source_text = r#"
+    export interface HelloWorld {
+      readonly color: string;
+      getUser(id: string): Promise<User>;
+    }
+
+    interface User {
+      readonly name: string;
+      nickName?: string;
+      tags?: string[];
+    }
+
+    enum Currency {
+      USD = "usd",
+      EURO = "eur"
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+    println!("{transformed_code}");
+
+    let expected_code = "package types
+
+type HelloWorld interface {
+\tColor() string
+\tGetUser(id string) (User, error)
+}
+
+type User struct {
+\tName     string   `json:\"name\"`
+\tNickName *string  `json:\"nickName,omitempty\"`
+\tTags     []string `json:\"tags,omitempty\"`
+}
+
+type Currency string
+
+const (
+\tCurrencyUSD  Currency = \"usd\"
+\tCurrencyEURO Currency = \"eur\"
+)
+";
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_holds_mixed_enum_values_in_variables() {
+    let source_text = r#"
+    enum Answer {
+      No = 0,
+      Yes = "yes",
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = "package types
+
+type Answer any
+
+var (
+\tAnswerNo  Answer = 0
+\tAnswerYes Answer = \"yes\"
+)
+";
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_counts_up_implicit_enum_values_after_an_initializer() {
+    let source_text = r#"
+    enum Priority {
+      Low = 5,
+      Medium,
+      High,
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = "package types
+
+type Priority int
+
+const (
+\tPriorityLow    Priority = 5
+\tPriorityMedium Priority = 6
+\tPriorityHigh   Priority = 7
+)
+";
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_sanitizes_names_and_keeps_json_keys() {
+    let source_text = r#"
+    interface Headers {
+      "content-type": string;
+      "1st"?: string;
+      type: string;
+    }
+
+    interface Request {
+      type: string;
+      send(func: string, range: number): void;
+    }
+
+    enum Status {
+      "in-progress" = "in-progress",
+      Done = "done",
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = "package types
+
+type Headers struct {
+\tContentType string  `json:\"content-type\"`
+\tX1st        *string `json:\"1st,omitempty\"`
+\tType        string  `json:\"type\"`
+}
+
+type Request interface {
+\tType() string
+\tSetType(type_ string)
+\tSend(func_ string, range_ float64)
+}
+
+type Status string
+
+const (
+\tStatusInProgress Status = \"in-progress\"
+\tStatusDone       Status = \"done\"
+)
+";
+    assert_eq!(transformed_code, expected_code);
+  }
+}
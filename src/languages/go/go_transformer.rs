@@ -0,0 +1,38 @@
+use oxc_ast::ast::Program;
+
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::{go_style, go_type_trait::GoType};
+
+pub struct GoTransformer;
+
+impl GoTransformer {
+  pub fn transform(ast_program: &Program, ctx: &TransformContext) -> String {
+    let mut output = String::new();
+
+    for statement in &ast_program.body {
+      let statement_code = statement.to_go_type(ctx);
+      output.push_str(&statement_code);
+    }
+
+    let import_paths = ctx.take_mapping_imports();
+    let import_banners = match import_paths.as_slice() {
+      [] => "".to_string(),
+      [import_path] => format!("import \"{import_path}\"\n\n"),
+      _ => format!(
+        "import (\n{})\n\n",
+        import_paths
+          .iter()
+          .map(|import_path| format!("{}\"{import_path}\"\n", go_style::INDENT_SPACE))
+          .collect::<String>()
+      ),
+    };
+
+    // gofmt keeps a single trailing newline
+    format!(
+      "package {}\n\n{import_banners}{}\n",
+      ctx.config.go.package_name,
+      output.trim_end()
+    )
+  }
+}
@@ -0,0 +1,334 @@
+use oxc_ast::ast::{
+  BindingPatternKind, Declaration, ExportNamedDeclaration, FormalParameters, PropertyKey,
+  Statement, TSEnumDeclaration, TSEnumMember, TSEnumMemberName, TSFunctionType,
+  TSInterfaceDeclaration, TSSignature, TSType, TSTypeReference,
+};
+
+use crate::{
+  config::transform_config::NamingConvention,
+  languages::{
+    go::{
+      go_enum_display_type_trait::GoEnumDisplayType,
+      go_fn_return_type_trait::GoFunctionReturnType,
+      go_identifier::{to_go_identifier, to_go_member_identifier},
+      go_struct_type_trait::GoStructType,
+      go_style,
+    },
+    shared::{
+      enum_values::get_enum_values_or_report, identifier::to_valid_identifier,
+      transform_context::TransformContext, type_mapping_trait::NativeTypeMapping,
+    },
+  },
+  utils::naming_utils::to_pascal_case,
+};
+
+use super::go_is_interface_type_trait::GoIsInterfaceType;
+
+pub trait GoType {
+  fn to_go_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl GoType for PropertyKey<'_> {
+  /// The key as it is in JSON, declared names go through `to_go_member_identifier`
+  fn to_go_type(&self, _ctx: &TransformContext) -> String {
+    self
+      .static_name()
+      .map(|name| name.to_string())
+      .unwrap_or_else(|| "unknown-PropertyKey".to_owned())
+  }
+}
+
+impl GoType for BindingPatternKind<'_> {
+  fn to_go_type(&self, _ctx: &TransformContext) -> String {
+    match self {
+      BindingPatternKind::BindingIdentifier(val) => to_go_identifier(&val.name),
+      _ => "unknown-BindingPatternKind".to_owned(),
+    }
+  }
+}
+
+impl GoType for TSTypeReference<'_> {
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.type_name.to_string();
+    if let Some(type_mapping) = ctx.config.go.type_mappings.get(&type_name) {
+      let type_args = self
+        .type_parameters
+        .as_ref()
+        .map(|x| {
+          x.params
+            .iter()
+            .map(|x| x.to_go_type(ctx))
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      ctx.add_mapping_imports(&type_mapping.imports);
+      return type_mapping.to_native_type(&type_args, "[", "]");
+    }
+
+    let type_param = |index: usize| {
+      self
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.get(index))
+        .map(|x| x.to_go_type(ctx))
+        .unwrap_or_else(|| "any".into())
+    };
+
+    match type_name.as_str() {
+      "Promise" => type_param(0),
+      "Array" | "ReadonlyArray" => format!("[]{}", type_param(0)),
+      "Record" | "Map" | "ReadonlyMap" => format!("map[{}]{}", type_param(0), type_param(1)),
+      "Set" | "ReadonlySet" => format!("map[{}]struct{{}}", type_param(0)),
+      _ => ctx.declared_type_name(&type_name, "go"),
+    }
+  }
+}
+
+impl GoType for TSFunctionType<'_> {
+  /// this is invoked from second level functions
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    let return_type = self.return_type.type_annotation.to_go_fn_return_type(ctx);
+    let param_types = self
+      .params
+      .items
+      .iter()
+      .map(|param| {
+        param
+          .pattern
+          .type_annotation
+          .as_ref()
+          .map(|t| t.type_annotation.to_go_type(ctx))
+          .unwrap_or_else(|| "any".to_string())
+      })
+      .collect::<Vec<_>>()
+      .join(", ");
+
+    format!("func({param_types}){return_type}")
+  }
+}
+
+impl GoType for TSType<'_> {
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSStringKeyword(_) => "string".to_string(),
+      TSType::TSNumberKeyword(_) => "float64".to_string(),
+      TSType::TSBooleanKeyword(_) => "bool".to_string(),
+      TSType::TSVoidKeyword(_) => "struct{}".to_string(),
+      TSType::TSObjectKeyword(_) => "map[string]any".to_string(),
+      TSType::TSTypeReference(ref_type) => ref_type.to_go_type(ctx),
+      TSType::TSFunctionType(fn_type) => fn_type.to_go_type(ctx),
+      TSType::TSArrayType(array_type) => {
+        let el_type = array_type.element_type.to_go_type(ctx);
+        format!("[]{el_type}")
+      }
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_go_type(ctx),
+      _ => "any".to_string(),
+    }
+  }
+}
+
+impl GoType for FormalParameters<'_> {
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    self
+      .items
+      .iter()
+      .map(|param| {
+        let type_annotation = param
+          .pattern
+          .type_annotation
+          .as_ref()
+          .map(|t| t.type_annotation.to_go_type(ctx))
+          .unwrap_or_else(|| "any".to_string());
+
+        format!("{} {}", param.pattern.kind.to_go_type(ctx), type_annotation)
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
+impl GoType for TSSignature<'_> {
+  /// Interface members, properties are exposed as getters (and setters when mutable)
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSSignature::TSPropertySignature(prop_sig) => {
+        let prop_name = prop_sig.key.to_go_type(ctx);
+        let method_name = to_go_member_identifier(&prop_name, ctx.config.go.naming.properties);
+
+        // If property is a arrow function
+        if let Some(annotation) = prop_sig.type_annotation.as_ref()
+          && let TSType::TSFunctionType(fn_type) = &annotation.type_annotation
+        {
+          let fn_return_type = fn_type
+            .return_type
+            .type_annotation
+            .to_go_fn_return_type(ctx);
+          let fn_params = fn_type.params.to_go_type(ctx);
+
+          return format!(
+            "{}{}({}){}",
+            go_style::INDENT_SPACE,
+            method_name,
+            fn_params,
+            fn_return_type
+          );
+        }
+
+        let return_type = prop_sig
+          .type_annotation
+          .as_ref()
+          .map(|annotation| annotation.type_annotation.to_go_fn_return_type(ctx))
+          .unwrap_or_else(|| " any".to_string());
+
+        let getter = format!("{}{}(){}", go_style::INDENT_SPACE, method_name, return_type);
+
+        if prop_sig.readonly {
+          getter
+        } else {
+          let type_annotation = prop_sig
+            .type_annotation
+            .as_ref()
+            .map(|annotation| annotation.type_annotation.to_go_type(ctx))
+            .unwrap_or_else(|| "any".to_string());
+
+          format!(
+            "{getter}\n{}Set{}({} {})",
+            go_style::INDENT_SPACE,
+            method_name,
+            to_go_identifier(&prop_name),
+            type_annotation
+          )
+        }
+      }
+      TSSignature::TSMethodSignature(method_sig) => {
+        let params = method_sig.params.to_go_type(ctx);
+
+        let return_type = method_sig
+          .return_type
+          .as_ref()
+          .map(|r| r.type_annotation.to_go_fn_return_type(ctx))
+          .unwrap_or_default();
+
+        let func_name = to_go_member_identifier(
+          &method_sig.key.to_go_type(ctx),
+          ctx.config.go.naming.methods,
+        );
+        format!(
+          "{}{}({}){}",
+          go_style::INDENT_SPACE,
+          func_name,
+          params,
+          return_type
+        )
+      }
+      _ => format!("{}// unknown-signature", go_style::INDENT_SPACE),
+    }
+  }
+}
+
+impl GoType for TSInterfaceDeclaration<'_> {
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    if self.is_go_interface_type() {
+      let interface_name = ctx.declared_type_name(&self.id.name, "go");
+      let body_data = self
+        .body
+        .body
+        .iter()
+        .map(|signature| signature.to_go_type(ctx))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+      format!("type {interface_name} interface {{\n{body_data}\n}}\n\n")
+    } else {
+      self.to_go_struct_type(ctx)
+    }
+  }
+}
+
+impl GoType for Declaration<'_> {
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      Declaration::TSInterfaceDeclaration(interface_decl) => interface_decl.to_go_type(ctx),
+      Declaration::TSEnumDeclaration(enum_decl) => enum_decl.to_go_type(ctx),
+      _ => "// unknown-declaration\n\n".to_string(),
+    }
+  }
+}
+
+impl GoType for ExportNamedDeclaration<'_> {
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    self
+      .declaration
+      .as_ref()
+      .map(|d| d.to_go_type(ctx))
+      .unwrap_or_else(|| "// unknown-export-named-declaration\n\n".to_string())
+  }
+}
+
+impl GoType for TSEnumMember<'_> {
+  fn to_go_type(&self, _ctx: &TransformContext) -> String {
+    match &self.id {
+      TSEnumMemberName::Identifier(enum_id) => enum_id.name.to_string(),
+      TSEnumMemberName::String(enum_string) => to_valid_identifier(&enum_string.value),
+    }
+  }
+}
+
+impl GoType for TSEnumDeclaration<'_> {
+  /// Enums become a named type with a block of typed constants, or of variables
+  /// when they mix strings and numbers
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    let enum_name = ctx.declared_type_name(&self.id.name, "go");
+    let enum_values = get_enum_values_or_report(ctx, self, &enum_name);
+    let enum_type = if enum_values.is_some() {
+      self.to_go_enum_display_type()
+    } else {
+      "int".to_string()
+    };
+
+    let enum_rows = self
+      .members
+      .iter()
+      .enumerate()
+      .map(|(index, x)| {
+        // members that are not constant are numbered in order
+        let enum_value = enum_values
+          .as_ref()
+          .map(|values| values[index].to_literal())
+          .unwrap_or_else(|| "iota".to_string());
+
+        vec![
+          format!(
+            "{}{}{}",
+            go_style::INDENT_SPACE,
+            enum_name,
+            to_go_member_name(&x.to_go_type(ctx), ctx.config.go.naming.enum_cases)
+          ),
+          enum_name.clone(),
+          format!("= {enum_value}"),
+        ]
+      })
+      .collect::<Vec<_>>();
+
+    let enum_constants = go_style::align_columns(&enum_rows).join("\n");
+    let keyword = if enum_type == "any" { "var" } else { "const" };
+    format!("type {enum_name} {enum_type}\n\n{keyword} (\n{enum_constants}\n)\n\n")
+  }
+}
+
+impl GoType for Statement<'_> {
+  fn to_go_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      Statement::ExportNamedDeclaration(export_decl) => export_decl.to_go_type(ctx),
+      Statement::TSInterfaceDeclaration(interface_decl) => interface_decl.to_go_type(ctx),
+      Statement::TSEnumDeclaration(enum_decl) => enum_decl.to_go_type(ctx),
+      _ => "// unknown-statement\n\n".to_string(),
+    }
+  }
+}
+
+/// A field, method or enum constant name in the configured naming `convention`,
+/// exported `PascalCase` by default
+pub fn to_go_member_name(name: &str, convention: Option<NamingConvention>) -> String {
+  convention.map_or_else(|| to_pascal_case(name), |convention| convention.apply(name))
+}
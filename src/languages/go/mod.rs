@@ -0,0 +1,12 @@
+pub mod go_enum_display_type_trait;
+pub mod go_fn_return_type_trait;
+pub mod go_identifier;
+pub mod go_is_interface_type_trait;
+pub mod go_struct_type_trait;
+pub mod go_style;
+pub mod go_transformer;
+pub mod go_type_trait;
+
+#[cfg(test)]
+#[path = "./go_test.rs"]
+mod go_test;
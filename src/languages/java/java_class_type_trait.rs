@@ -0,0 +1,140 @@
+use oxc_ast::ast::{TSInterfaceDeclaration, TSSignature};
+
+use crate::{
+  config::transform_config::JavaClassStyle,
+  languages::{
+    java::{
+      java_imports::import_java_type,
+      java_style,
+      java_type_trait::{
+        JavaType, get_java_json_property, to_java_accessor_suffix, to_java_type_parameters,
+      },
+    },
+    shared::transform_context::TransformContext,
+  },
+};
+
+pub trait JavaClassType {
+  fn to_java_class_type(&self, ctx: &TransformContext) -> String;
+}
+
+struct JavaField {
+  name: String,
+  /// Jackson annotations followed by a space, such as the `@JsonProperty` of a
+  /// field renamed from its TS key
+  annotations: String,
+  type_name: String,
+  readonly: bool,
+}
+
+impl JavaClassType for TSInterfaceDeclaration<'_> {
+  fn to_java_class_type(&self, ctx: &TransformContext) -> String {
+    let class_name = self.id.name.to_string();
+    let fields = self
+      .body
+      .body
+      .iter()
+      .filter_map(|signature| match signature {
+        // the keys the class does not declare are collected into a map
+        TSSignature::TSIndexSignature(index_sig) => Some(JavaField {
+          name: "additionalProperties".to_string(),
+          annotations: format!(
+            "@{} @{} ",
+            import_java_type(ctx, "JsonAnyGetter"),
+            import_java_type(ctx, "JsonAnySetter")
+          ),
+          type_name: index_sig.to_java_type(ctx),
+          readonly: index_sig.readonly,
+        }),
+        TSSignature::TSPropertySignature(prop_sig) => Some(JavaField {
+          name: prop_sig.key.to_java_type(ctx),
+          annotations: get_java_json_property(ctx, &prop_sig.key),
+          type_name: prop_sig
+            .type_annotation
+            .as_ref()
+            .map(|annotation| annotation.type_annotation.to_java_type(ctx))
+            .unwrap_or_else(|| "Object".to_string()),
+          readonly: prop_sig.readonly,
+        }),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    let type_parameters = to_java_type_parameters(self.type_parameters.as_deref());
+    match ctx.config.java.class_style {
+      JavaClassStyle::Record => to_java_record(&class_name, &type_parameters, &fields),
+      JavaClassStyle::Pojo => to_java_pojo(&class_name, &type_parameters, &fields),
+    }
+  }
+}
+
+fn to_java_record(class_name: &str, type_parameters: &str, fields: &[JavaField]) -> String {
+  let components = fields
+    .iter()
+    .map(|field| {
+      format!(
+        "{}{}{} {}",
+        java_style::INDENT_SPACE,
+        field.annotations,
+        field.type_name,
+        field.name
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(",\n");
+
+  format!("public record {class_name}{type_parameters}(\n{components}\n) {{\n}}\n")
+}
+
+fn to_java_pojo(class_name: &str, type_parameters: &str, fields: &[JavaField]) -> String {
+  let indent = java_style::INDENT_SPACE;
+
+  let declarations = fields
+    .iter()
+    .map(|field| {
+      let final_modifier = if field.readonly { "final " } else { "" };
+      format!(
+        "{indent}{}private {final_modifier}{} {};",
+        field.annotations, field.type_name, field.name
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+
+  let constructor_params = fields
+    .iter()
+    .map(|field| format!("{} {}", field.type_name, field.name))
+    .collect::<Vec<_>>()
+    .join(", ");
+
+  let constructor_body = fields
+    .iter()
+    .map(|field| format!("{indent}{indent}this.{0} = {0};\n", field.name))
+    .collect::<String>();
+
+  let accessors = fields
+    .iter()
+    .map(|field| {
+      let accessor_suffix = to_java_accessor_suffix(&field.name);
+      let getter = format!(
+        "\n{indent}public {} get{accessor_suffix}() {{\n{indent}{indent}return {};\n{indent}}}\n",
+        field.type_name, field.name
+      );
+
+      if field.readonly {
+        getter
+      } else {
+        format!(
+          "{getter}\n{indent}public void set{accessor_suffix}({} {1}) {{\n{indent}{indent}this.{1} = {1};\n{indent}}}\n",
+          field.type_name, field.name
+        )
+      }
+    })
+    .collect::<String>();
+
+  format!(
+    "public class {class_name}{type_parameters} {{\n{declarations}\n\n\
+    {indent}public {class_name}({constructor_params}) {{\n{constructor_body}{indent}}}\n\
+    {accessors}}}\n"
+  )
+}
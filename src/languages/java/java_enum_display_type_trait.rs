@@ -0,0 +1,58 @@
+use oxc_ast::ast::TSEnumDeclaration;
+
+use crate::languages::shared::enum_values::{EnumValue, EnumValues, is_heterogeneous};
+
+pub trait JavaEnumDisplayType {
+  fn to_java_enum_display_type(&self) -> String;
+}
+
+impl JavaEnumDisplayType for TSEnumDeclaration<'_> {
+  fn to_java_enum_display_type(&self) -> String {
+    let values = self
+      .get_enum_values()
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>();
+
+    // enums mixing strings and numbers keep their values boxed
+    if is_heterogeneous(&values) {
+      return "Object".to_string();
+    }
+
+    let numbers = values
+      .iter()
+      .filter_map(|x| match x {
+        EnumValue::Number(value) => Some(*value),
+        EnumValue::String(_) => None,
+      })
+      .collect::<Vec<_>>();
+
+    if numbers.is_empty() {
+      return "String".to_string();
+    }
+
+    if numbers.iter().any(|value| value.fract() != 0.0) {
+      return "Double".to_string();
+    }
+
+    let fits_integer = numbers
+      .iter()
+      .all(|x| *x >= i32::MIN as f64 && *x <= i32::MAX as f64);
+
+    if fits_integer {
+      "Integer".to_string()
+    } else {
+      "Long".to_string()
+    }
+  }
+}
+
+/// The value as an argument of an enum constant whose `value` is `enum_type`
+pub fn to_java_enum_literal(value: &EnumValue, enum_type: &str) -> String {
+  match (value, enum_type) {
+    // a `Double` or `Long` parameter does not take an `int` literal
+    (EnumValue::Number(value), "Double") => format!("{value:?}"),
+    (EnumValue::Number(value), "Long") => format!("{value}L"),
+    (value, _) => value.to_literal(),
+  }
+}
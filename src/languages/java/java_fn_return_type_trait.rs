@@ -0,0 +1,18 @@
+use oxc_ast::ast::TSType;
+
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::java_type_trait::JavaType;
+
+pub trait JavaFunctionReturnType {
+  fn to_java_fn_return_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl JavaFunctionReturnType for TSType<'_> {
+  fn to_java_fn_return_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSVoidKeyword(_) => "void".to_string(),
+      _ => self.to_java_type(ctx),
+    }
+  }
+}
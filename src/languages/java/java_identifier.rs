@@ -0,0 +1,72 @@
+use crate::languages::shared::identifier::{suffix_reserved_word, to_valid_identifier};
+
+/// Keywords and literals that cannot be used as a name, contextual keywords such as `record` can
+const JAVA_RESERVED_WORDS: &[&str] = &[
+  "_",
+  "abstract",
+  "assert",
+  "boolean",
+  "break",
+  "byte",
+  "case",
+  "catch",
+  "char",
+  "class",
+  "const",
+  "continue",
+  "default",
+  "do",
+  "double",
+  "else",
+  "enum",
+  "extends",
+  "false",
+  "final",
+  "finally",
+  "float",
+  "for",
+  "goto",
+  "if",
+  "implements",
+  "import",
+  "instanceof",
+  "int",
+  "interface",
+  "long",
+  "native",
+  "new",
+  "null",
+  "package",
+  "private",
+  "protected",
+  "public",
+  "return",
+  "short",
+  "static",
+  "strictfp",
+  "super",
+  "switch",
+  "synchronized",
+  "this",
+  "throw",
+  "throws",
+  "transient",
+  "true",
+  "try",
+  "void",
+  "volatile",
+  "while",
+];
+
+/// A TS name as a Java identifier, sanitized and suffixed, `default` -> `default_`
+pub fn to_java_identifier(name: &str) -> String {
+  suffix_reserved_word(&to_valid_identifier(name), JAVA_RESERVED_WORDS)
+}
+
+/// The name an identifier suffixed by `to_java_identifier` declares, `default_` -> `default`
+pub fn unescape_java_identifier(identifier: &str) -> &str {
+  match identifier.strip_suffix('_') {
+    Some(name) if JAVA_RESERVED_WORDS.contains(&name) => name,
+    _ => identifier,
+  }
+}
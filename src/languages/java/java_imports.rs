@@ -0,0 +1,48 @@
+use crate::languages::shared::transform_context::TransformContext;
+
+/// Built-in types that need an import, in the order they are emitted
+pub const JAVA_IMPORTS: [(&str, &str); 18] = [
+  (
+    "JsonAnyGetter",
+    "com.fasterxml.jackson.annotation.JsonAnyGetter",
+  ),
+  (
+    "JsonAnySetter",
+    "com.fasterxml.jackson.annotation.JsonAnySetter",
+  ),
+  (
+    "JsonCreator",
+    "com.fasterxml.jackson.annotation.JsonCreator",
+  ),
+  (
+    "JsonProperty",
+    "com.fasterxml.jackson.annotation.JsonProperty",
+  ),
+  ("JsonValue", "com.fasterxml.jackson.annotation.JsonValue"),
+  ("URI", "java.net.URI"),
+  ("Instant", "java.time.Instant"),
+  (
+    "CompletableFuture",
+    "java.util.concurrent.CompletableFuture",
+  ),
+  ("Flow", "java.util.concurrent.Flow"),
+  ("BiConsumer", "java.util.function.BiConsumer"),
+  ("BiFunction", "java.util.function.BiFunction"),
+  ("Consumer", "java.util.function.Consumer"),
+  ("Function", "java.util.function.Function"),
+  ("Supplier", "java.util.function.Supplier"),
+  ("List", "java.util.List"),
+  ("Map", "java.util.Map"),
+  ("Set", "java.util.Set"),
+  ("Pattern", "java.util.regex.Pattern"),
+];
+
+/// How the emitted code refers to `type_name` of `JAVA_IMPORTS`, fully
+/// qualified when a declaration of the file has the same name
+pub fn import_java_type(ctx: &TransformContext, type_name: &str) -> String {
+  let import_path = JAVA_IMPORTS
+    .iter()
+    .find(|(name, _)| *name == type_name)
+    .map_or(type_name, |(_, import_path)| import_path);
+  ctx.import_type(type_name, import_path)
+}
@@ -0,0 +1,467 @@
+#[cfg(test)]
+mod tests {
+
+  use oxc_allocator::Allocator;
+  use oxc_parser::{ParseOptions, Parser};
+  use oxc_span::SourceType;
+
+  use crate::{
+    config::transform_config::{JavaClassStyle, TransformConfig},
+    languages::{
+      java::java_transformer::JavaTransformer, shared::transform_context::TransformContext,
+    },
+  };
+
+  fn transform(source_text: &str, config: &TransformConfig) -> Vec<(String, String)> {
+    transform_with_diagnostics(source_text, config).0
+  }
+
+  fn transform_with_diagnostics(
+    source_text: &str,
+    config: &TransformConfig,
+  ) -> (Vec<(String, String)>, Vec<String>) {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, source_text, source_type)
+      .with_options(ParseOptions {
+        ..ParseOptions::default()
+      })
+      .parse();
+
+    let program = parser_ret.program;
+    let ctx = TransformContext::new(&program, config);
+    let transformed_files = JavaTransformer::transform(&program, &ctx);
+    (transformed_files, ctx.take_diagnostics())
+  }
+
+  #[test]
+  fn it_converts_to_java_type() {
+    let source_text = r#"
+    export interface HelloWorld {
+      readonly color: string;
+      getUser(id: string): Promise<User>;
+      onChange(listener: (value: number) => void): void;
+    }
+
+    interface User {
+      readonly name: string;
+      tags: string[];
+    }
+
+    enum Currency {
+      USD = 10,
+      EUR = 42
+    }
+    "#;
+
+    let transformed_files = transform(source_text, &TransformConfig::default());
+    let file_names = transformed_files
+      .iter()
+      .map(|(file_name, _)| file_name.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      file_names,
+      vec!["HelloWorld.java", "User.java", "Currency.java"]
+    );
+
+    let expected_interface = r#"import java.util.concurrent.CompletableFuture;
+import java.util.function.Consumer;
+
+public interface HelloWorld {
+    String getColor();
+    CompletableFuture<User> getUser(String id);
+    void onChange(Consumer<Double> listener);
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_interface);
+
+    let expected_record = r#"import java.util.List;
+
+public record User(
+    String name,
+    List<String> tags
+) {
+}
+"#;
+    assert_eq!(transformed_files[1].1, expected_record);
+
+    let expected_enum = r#"import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum Currency {
+    USD(10),
+    EUR(42);
+
+    private final Integer value;
+
+    Currency(Integer value) {
+        this.value = value;
+    }
+
+    @JsonValue
+    public Integer getValue() {
+        return value;
+    }
+
+    @JsonCreator
+    public static Currency fromValue(Integer value) {
+        for (Currency entry : values()) {
+            if (entry.value.equals(value)) {
+                return entry;
+            }
+        }
+        throw new IllegalArgumentException("Unknown Currency value: " + value);
+    }
+}
+"#;
+    assert_eq!(transformed_files[2].1, expected_enum);
+  }
+
+  #[test]
+  fn it_converts_to_java_pojo() {
+    let source_text = r#"
+    interface User {
+      readonly name: string;
+      age?: number;
+    }
+    "#;
+
+    let mut config = TransformConfig::default();
+    config.java.class_style = JavaClassStyle::Pojo;
+    let transformed_files = transform(source_text, &config);
+
+    let expected_code = r#"public class User {
+    private final String name;
+    private Double age;
+
+    public User(String name, Double age) {
+        this.name = name;
+        this.age = age;
+    }
+
+    public String getName() {
+        return name;
+    }
+
+    public Double getAge() {
+        return age;
+    }
+
+    public void setAge(Double age) {
+        this.age = age;
+    }
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_code);
+  }
+
+  #[test]
+  fn it_applies_type_mappings_with_imports() {
+    let source_text = r#"
+    interface Invoice {
+      readonly id: Uuid;
+      readonly total: Decimal;
+      readonly lines: Page<Decimal>;
+    }
+    "#;
+
+    let config: TransformConfig = serde_json::from_str(
+      r#"{
+        "java": {
+          "typeMappings": {
+            "Decimal": { "type": "BigDecimal", "imports": ["java.math.BigDecimal"] },
+            "Uuid": { "type": "UUID", "imports": ["java.util.UUID"] },
+            "Page": { "type": "Paged" }
+          }
+        }
+      }"#,
+    )
+    .unwrap();
+    let transformed_files = transform(source_text, &config);
+
+    let expected_code = r#"import java.math.BigDecimal;
+import java.util.UUID;
+
+public record Invoice(
+    UUID id,
+    BigDecimal total,
+    Paged<BigDecimal> lines
+) {
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_code);
+  }
+
+  #[test]
+  fn it_sanitizes_names_and_keeps_json_keys() {
+    let source_text = r#"
+    interface Headers {
+      readonly "content-type": string;
+      default: boolean;
+    }
+
+    interface Toggle {
+      default: boolean;
+      set(native: boolean): void;
+    }
+
+    enum MediaType {
+      "text-plain",
+      Json,
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.java.class_style = JavaClassStyle::Pojo;
+    let transformed_files = transform(source_text, &config);
+
+    let expected_pojo = r#"import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class Headers {
+    @JsonProperty("content-type") private final String contentType;
+    @JsonProperty("default") private Boolean default_;
+
+    public Headers(String contentType, Boolean default_) {
+        this.contentType = contentType;
+        this.default_ = default_;
+    }
+
+    public String getContentType() {
+        return contentType;
+    }
+
+    public Boolean getDefault() {
+        return default_;
+    }
+
+    public void setDefault(Boolean default_) {
+        this.default_ = default_;
+    }
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_pojo);
+
+    let expected_interface = r#"import com.fasterxml.jackson.annotation.JsonProperty;
+
+public interface Toggle {
+    @JsonProperty("default") Boolean getDefault();
+    void setDefault(Boolean default_);
+    void set(Boolean native_);
+}
+"#;
+    assert_eq!(transformed_files[1].1, expected_interface);
+
+    let expected_enum = r#"import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum MediaType {
+    textPlain(0),
+    Json(1);
+
+    private final Integer value;
+
+    MediaType(Integer value) {
+        this.value = value;
+    }
+
+    @JsonValue
+    public Integer getValue() {
+        return value;
+    }
+
+    @JsonCreator
+    public static MediaType fromValue(Integer value) {
+        for (MediaType entry : values()) {
+            if (entry.value.equals(value)) {
+                return entry;
+            }
+        }
+        throw new IllegalArgumentException("Unknown MediaType value: " + value);
+    }
+}
+"#;
+    assert_eq!(transformed_files[2].1, expected_enum);
+  }
+
+  #[test]
+  fn it_collects_unknown_keys_into_a_map() {
+    let source_text = r#"
+    interface Headers {
+      [name: string]: string;
+    }
+
+    interface Order {
+      readonly id: string;
+      readonly [key: string]: number | string;
+    }
+    "#;
+    let transformed_files = transform(source_text, &TransformConfig::default());
+
+    let expected_dictionary = r#"import com.fasterxml.jackson.annotation.JsonAnyGetter;
+import com.fasterxml.jackson.annotation.JsonAnySetter;
+import java.util.Map;
+
+public record Headers(
+    @JsonAnyGetter @JsonAnySetter Map<String, String> additionalProperties
+) {
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_dictionary);
+
+    let expected_record = r#"import com.fasterxml.jackson.annotation.JsonAnyGetter;
+import com.fasterxml.jackson.annotation.JsonAnySetter;
+import java.util.Map;
+
+public record Order(
+    String id,
+    @JsonAnyGetter @JsonAnySetter Map<String, Object> additionalProperties
+) {
+}
+"#;
+    assert_eq!(transformed_files[1].1, expected_record);
+  }
+
+  #[test]
+  fn it_boxes_mixed_enum_values() {
+    let source_text = r#"
+    enum Answer {
+      No = 0,
+      Yes = "yes",
+    }
+    "#;
+    let transformed_files = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum Answer {
+    No(0),
+    Yes("yes");
+
+    private final Object value;
+
+    Answer(Object value) {
+        this.value = value;
+    }
+
+    @JsonValue
+    public Object getValue() {
+        return value;
+    }
+
+    @JsonCreator
+    public static Answer fromValue(Object value) {
+        for (Answer entry : values()) {
+            if (entry.value.equals(value)) {
+                return entry;
+            }
+        }
+        throw new IllegalArgumentException("Unknown Answer value: " + value);
+    }
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_code);
+  }
+
+  #[test]
+  fn it_counts_up_implicit_enum_values_after_an_initializer() {
+    let source_text = r#"
+    enum Priority {
+      Low = 5,
+      Medium,
+      High,
+    }
+    "#;
+    let transformed_files = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum Priority {
+    Low(5),
+    Medium(6),
+    High(7);
+
+    private final Integer value;
+
+    Priority(Integer value) {
+        this.value = value;
+    }
+
+    @JsonValue
+    public Integer getValue() {
+        return value;
+    }
+
+    @JsonCreator
+    public static Priority fromValue(Integer value) {
+        for (Priority entry : values()) {
+            if (entry.value.equals(value)) {
+                return entry;
+            }
+        }
+        throw new IllegalArgumentException("Unknown Priority value: " + value);
+    }
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_code);
+  }
+
+  #[test]
+  fn it_converts_well_known_types_and_reports_undeclared_ones() {
+    let source_text = r#"
+    interface Attachment<T> {
+      readonly createdAt: Date;
+      readonly bytes: Uint8Array;
+      readonly size: bigint;
+      readonly link: URL;
+      readonly updates: AsyncIterable<T>;
+      readonly owner: Account;
+    }
+    "#;
+    let (transformed_files, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import java.net.URI;
+import java.time.Instant;
+import java.util.concurrent.Flow;
+
+public record Attachment<T>(
+    Instant createdAt,
+    byte[] bytes,
+    Long size,
+    URI link,
+    Flow.Publisher<T> updates,
+    Account owner
+) {
+}
+"#;
+    assert_eq!(transformed_files[0].1, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`Account` is not declared in this file, it is emitted as it is"]
+    );
+  }
+
+  #[test]
+  fn it_reports_type_aliases() {
+    let source_text = r#"
+    type UserId = string;
+
+    interface User {
+      readonly id: UserId;
+    }
+    "#;
+    let (transformed_files, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    assert_eq!(transformed_files.len(), 1);
+    assert_eq!(transformed_files[0].0, "User.java");
+    assert_eq!(
+      diagnostics,
+      vec![
+        "Java has no type aliases, `UserId` is left out and its references are emitted as they are"
+      ]
+    );
+  }
+}
@@ -0,0 +1,61 @@
+use oxc_ast::ast::{Declaration, Program, Statement};
+
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::{java_imports::JAVA_IMPORTS, java_type_trait::JavaType};
+
+pub struct JavaTransformer;
+
+impl JavaTransformer {
+  /// Java allows a single public type per file, so every declaration
+  /// is returned as its own `(file name, code)` pair
+  pub fn transform(ast_program: &Program, ctx: &TransformContext) -> Vec<(String, String)> {
+    ast_program
+      .body
+      .iter()
+      .filter_map(|statement| {
+        let declaration = match statement {
+          Statement::ExportNamedDeclaration(export_decl) => export_decl.declaration.as_ref(),
+          _ => statement.as_declaration(),
+        }?;
+
+        let (type_name, code) = match declaration {
+          Declaration::TSInterfaceDeclaration(interface_decl) => (
+            interface_decl.id.name.to_string(),
+            interface_decl.to_java_type(ctx),
+          ),
+          Declaration::TSEnumDeclaration(enum_decl) => {
+            (enum_decl.id.name.to_string(), enum_decl.to_java_type(ctx))
+          }
+          Declaration::TSTypeAliasDeclaration(alias_decl) => {
+            ctx.report(format!(
+              "Java has no type aliases, `{}` is left out and its references are emitted as they are",
+              alias_decl.id.name
+            ));
+            return None;
+          }
+          _ => return None,
+        };
+
+        // types named like a declaration are referred to by their qualified name
+        let imported_types = ctx.take_imported_types();
+        let import_banners = JAVA_IMPORTS
+          .iter()
+          .filter(|(type_name, _)| {
+            imported_types.contains(*type_name) && !ctx.is_declared(type_name)
+          })
+          .map(|(_, import_path)| import_path.to_string())
+          .chain(ctx.take_mapping_imports())
+          .map(|import_path| format!("import {import_path};\n"))
+          .collect::<String>();
+
+        let code = if import_banners.is_empty() {
+          code
+        } else {
+          format!("{import_banners}\n{code}")
+        };
+        Some((format!("{type_name}.java"), code))
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,426 @@
+use oxc_ast::ast::{
+  BindingPatternKind, FormalParameters, PropertyKey, TSEnumDeclaration, TSEnumMember,
+  TSEnumMemberName, TSFunctionType, TSIndexSignature, TSInterfaceDeclaration, TSSignature, TSType,
+  TSTypeParameterDeclaration, TSTypeReference,
+};
+
+use crate::languages::{
+  java::{
+    java_class_type_trait::JavaClassType,
+    java_enum_display_type_trait::{JavaEnumDisplayType, to_java_enum_literal},
+    java_fn_return_type_trait::JavaFunctionReturnType,
+    java_identifier::{to_java_identifier, unescape_java_identifier},
+    java_imports::import_java_type,
+    java_style,
+  },
+  shared::{
+    enum_values::get_enum_values_or_report, stream_types::STREAM_TYPE_NAMES,
+    transform_context::TransformContext, type_mapping_trait::NativeTypeMapping,
+  },
+};
+
+use super::java_is_interface_type_trait::JavaIsInterfaceType;
+
+pub trait JavaType {
+  fn to_java_type(&self, ctx: &TransformContext) -> String;
+}
+
+/// `name` -> `Name`, used to build getter and setter names, `default_` -> `Default`
+pub fn to_java_accessor_suffix(name: &str) -> String {
+  let mut chars = unescape_java_identifier(name).chars();
+  chars
+    .next()
+    .map(|first| format!("{}{}", first.to_uppercase(), chars.as_str()))
+    .unwrap_or_default()
+}
+
+/// `@JsonProperty("content-type") ` for a member declared under another name
+/// than its TS key, so the JSON key stays the same
+pub fn get_java_json_property(ctx: &TransformContext, key: &PropertyKey) -> String {
+  match key.static_name() {
+    Some(key_name) if key.to_java_type(ctx) != key_name => {
+      format!(
+        "@{}(\"{key_name}\") ",
+        import_java_type(ctx, "JsonProperty")
+      )
+    }
+    _ => "".to_string(),
+  }
+}
+
+/// Generic params of a declaration, `<K, V>`, or empty
+pub fn to_java_type_parameters(type_parameters: Option<&TSTypeParameterDeclaration>) -> String {
+  match type_parameters {
+    Some(type_parameters) if !type_parameters.params.is_empty() => format!(
+      "<{}>",
+      type_parameters
+        .params
+        .iter()
+        .map(|param| param.name.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+    ),
+    _ => "".to_string(),
+  }
+}
+
+impl JavaType for PropertyKey<'_> {
+  fn to_java_type(&self, _ctx: &TransformContext) -> String {
+    self
+      .static_name()
+      .map(|name| to_java_identifier(&name))
+      .unwrap_or_else(|| "unknown-PropertyKey".to_owned())
+  }
+}
+
+impl JavaType for BindingPatternKind<'_> {
+  fn to_java_type(&self, _ctx: &TransformContext) -> String {
+    match self {
+      BindingPatternKind::BindingIdentifier(val) => to_java_identifier(&val.name),
+      _ => "unknown-BindingPatternKind".to_owned(),
+    }
+  }
+}
+
+impl JavaType for TSTypeReference<'_> {
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.type_name.to_string();
+    if let Some(type_mapping) = ctx.config.java.type_mappings.get(&type_name) {
+      let type_args = self
+        .type_parameters
+        .as_ref()
+        .map(|x| {
+          x.params
+            .iter()
+            .map(|x| x.to_java_type(ctx))
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      ctx.add_mapping_imports(&type_mapping.imports);
+      return type_mapping.to_native_type(&type_args, "<", ">");
+    }
+
+    let type_param = |index: usize| {
+      self
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.get(index))
+        .map(|x| x.to_java_type(ctx))
+        .unwrap_or_else(|| "Object".into())
+    };
+
+    match type_name.as_str() {
+      "Promise" => format!(
+        "{}<{}>",
+        import_java_type(ctx, "CompletableFuture"),
+        type_param(0)
+      ),
+      "Array" | "ReadonlyArray" => format!("{}<{}>", import_java_type(ctx, "List"), type_param(0)),
+      "Record" | "Map" | "ReadonlyMap" => format!(
+        "{}<{}, {}>",
+        import_java_type(ctx, "Map"),
+        type_param(0),
+        type_param(1)
+      ),
+      "Set" | "ReadonlySet" => format!("{}<{}>", import_java_type(ctx, "Set"), type_param(0)),
+      "Date" => import_java_type(ctx, "Instant"),
+      "Uint8Array" | "ArrayBuffer" => "byte[]".to_string(),
+      name if STREAM_TYPE_NAMES.contains(&name) => {
+        format!(
+          "{}.Publisher<{}>",
+          import_java_type(ctx, "Flow"),
+          type_param(0)
+        )
+      }
+      "URL" => import_java_type(ctx, "URI"),
+      "Error" => "Throwable".to_string(),
+      "RegExp" => import_java_type(ctx, "Pattern"),
+      _ => {
+        if !ctx.is_declared(&type_name) && !ctx.is_type_parameter(&type_name) {
+          ctx.report(format!(
+            "`{type_name}` is not declared in this file, it is emitted as it is"
+          ));
+        }
+        type_name
+      }
+    }
+  }
+}
+
+impl JavaType for TSFunctionType<'_> {
+  /// Function values are mapped to the matching `java.util.function` interface
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    let return_type = &self.return_type.type_annotation;
+    let is_void = matches!(return_type, TSType::TSVoidKeyword(_));
+    let type_name = return_type.to_java_type(ctx);
+
+    let param_types = self
+      .params
+      .items
+      .iter()
+      .map(|param| {
+        param
+          .pattern
+          .type_annotation
+          .as_ref()
+          .map(|t| t.type_annotation.to_java_type(ctx))
+          .unwrap_or_else(|| "Object".to_string())
+      })
+      .collect::<Vec<_>>();
+
+    let interface_name = |type_name| import_java_type(ctx, type_name);
+    match (param_types.as_slice(), is_void) {
+      ([], true) => "Runnable".to_string(),
+      ([], false) => format!("{}<{type_name}>", interface_name("Supplier")),
+      ([arg], true) => format!("{}<{arg}>", interface_name("Consumer")),
+      ([arg], false) => format!("{}<{arg}, {type_name}>", interface_name("Function")),
+      ([arg1, arg2], true) => format!("{}<{arg1}, {arg2}>", interface_name("BiConsumer")),
+      ([arg1, arg2], false) => format!(
+        "{}<{arg1}, {arg2}, {type_name}>",
+        interface_name("BiFunction")
+      ),
+      _ => "Object".to_string(),
+    }
+  }
+}
+
+impl JavaType for TSType<'_> {
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSStringKeyword(_) => "String".to_string(),
+      TSType::TSNumberKeyword(_) => "Double".to_string(),
+      TSType::TSBooleanKeyword(_) => "Boolean".to_string(),
+      TSType::TSBigIntKeyword(_) => "Long".to_string(),
+      TSType::TSVoidKeyword(_) => "Void".to_string(),
+      TSType::TSObjectKeyword(_) => format!("{}<String, Object>", import_java_type(ctx, "Map")),
+      TSType::TSTypeReference(ref_type) => ref_type.to_java_type(ctx),
+      TSType::TSFunctionType(fn_type) => fn_type.to_java_type(ctx),
+      TSType::TSArrayType(array_type) => {
+        let el_type = array_type.element_type.to_java_type(ctx);
+        format!("{}<{el_type}>", import_java_type(ctx, "List"))
+      }
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_java_type(ctx),
+      _ => "Object".to_string(),
+    }
+  }
+}
+
+impl JavaType for TSIndexSignature<'_> {
+  /// `[key: string]: T` as the map of the keys an interface does not declare
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    format!(
+      "{}<String, {}>",
+      import_java_type(ctx, "Map"),
+      self.type_annotation.type_annotation.to_java_type(ctx)
+    )
+  }
+}
+
+impl JavaType for FormalParameters<'_> {
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    self
+      .items
+      .iter()
+      .map(|param| {
+        let type_annotation = param
+          .pattern
+          .type_annotation
+          .as_ref()
+          .map(|t| t.type_annotation.to_java_type(ctx))
+          .unwrap_or_else(|| "Object".to_string());
+
+        format!(
+          "{} {}",
+          type_annotation,
+          param.pattern.kind.to_java_type(ctx)
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
+impl JavaType for TSSignature<'_> {
+  /// Interface members, properties are exposed as getters (and setters when mutable)
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSSignature::TSPropertySignature(prop_sig) => {
+        let prop_name = prop_sig.key.to_java_type(ctx);
+
+        // If property is a arrow function
+        if let Some(annotation) = prop_sig.type_annotation.as_ref()
+          && let TSType::TSFunctionType(fn_type) = &annotation.type_annotation
+        {
+          let fn_return_type = fn_type
+            .return_type
+            .type_annotation
+            .to_java_fn_return_type(ctx);
+          let fn_params = fn_type.params.to_java_type(ctx);
+
+          return format!(
+            "{}{} {}({});",
+            java_style::INDENT_SPACE,
+            fn_return_type,
+            prop_name,
+            fn_params
+          );
+        }
+
+        let type_annotation = prop_sig
+          .type_annotation
+          .as_ref()
+          .map(|annotation| annotation.type_annotation.to_java_type(ctx))
+          .unwrap_or_else(|| "Object".to_string());
+
+        let accessor_suffix = to_java_accessor_suffix(&prop_name);
+        let getter = format!(
+          "{}{}{} get{}();",
+          java_style::INDENT_SPACE,
+          get_java_json_property(ctx, &prop_sig.key),
+          type_annotation,
+          accessor_suffix
+        );
+
+        if prop_sig.readonly {
+          getter
+        } else {
+          format!(
+            "{getter}\n{}void set{}({} {});",
+            java_style::INDENT_SPACE,
+            accessor_suffix,
+            type_annotation,
+            prop_name
+          )
+        }
+      }
+      TSSignature::TSMethodSignature(method_sig) => {
+        let params = method_sig.params.to_java_type(ctx);
+
+        let return_type = method_sig
+          .return_type
+          .as_ref()
+          .map(|r| r.type_annotation.to_java_fn_return_type(ctx))
+          .unwrap_or_else(|| "void".to_string());
+
+        let func_name = method_sig.key.to_java_type(ctx);
+        format!(
+          "{}{} {}({});",
+          java_style::INDENT_SPACE,
+          return_type,
+          func_name,
+          params
+        )
+      }
+      TSSignature::TSIndexSignature(index_sig) => format!(
+        "{}{} getAdditionalProperties();",
+        java_style::INDENT_SPACE,
+        index_sig.to_java_type(ctx)
+      ),
+      _ => "// unknown-signature".to_owned(),
+    }
+  }
+}
+
+impl JavaType for TSInterfaceDeclaration<'_> {
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    if self.is_java_interface_type() {
+      let interface_name = self.id.name.to_string();
+      let body_data = self
+        .body
+        .body
+        .iter()
+        .map(|signature| signature.to_java_type(ctx))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+      format!(
+        "public interface {interface_name}{} {{\n{body_data}\n}}\n",
+        to_java_type_parameters(self.type_parameters.as_deref())
+      )
+    } else {
+      self.to_java_class_type(ctx)
+    }
+  }
+}
+
+impl JavaType for TSEnumMember<'_> {
+  fn to_java_type(&self, _ctx: &TransformContext) -> String {
+    to_java_identifier(&get_enum_member_name(self))
+  }
+}
+
+fn get_enum_member_name(member: &TSEnumMember) -> String {
+  match &member.id {
+    TSEnumMemberName::Identifier(enum_id) => enum_id.name.to_string(),
+    TSEnumMemberName::String(enum_string) => enum_string.value.to_string(),
+  }
+}
+
+impl JavaType for TSEnumDeclaration<'_> {
+  fn to_java_type(&self, ctx: &TransformContext) -> String {
+    let enum_name = self.id.to_string();
+    let indent = java_style::INDENT_SPACE;
+
+    let Some(enum_values) = get_enum_values_or_report(ctx, self, &enum_name) else {
+      // Jackson writes constants by name, sanitized ones keep their TS name
+      let enum_entries = self
+        .members
+        .iter()
+        .map(|x| {
+          let constant_name = x.to_java_type(ctx);
+          let member_name = get_enum_member_name(x);
+          if constant_name == member_name {
+            format!("{indent}{constant_name}")
+          } else {
+            format!(
+              "{indent}@{}(\"{member_name}\") {constant_name}",
+              import_java_type(ctx, "JsonProperty")
+            )
+          }
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+      return format!("public enum {enum_name} {{\n{enum_entries}\n}}\n");
+    };
+
+    let enum_type = self.to_java_enum_display_type();
+    // Jackson writes and reads the constants by their value
+    let json_value = import_java_type(ctx, "JsonValue");
+    let json_creator = import_java_type(ctx, "JsonCreator");
+    let enum_entries = self
+      .members
+      .iter()
+      .zip(&enum_values)
+      .map(|(x, enum_value)| {
+        format!(
+          "{indent}{}({})",
+          x.to_java_type(ctx),
+          to_java_enum_literal(enum_value, &enum_type)
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n");
+
+    format!(
+      "public enum {enum_name} {{\n{enum_entries};\n\n\
+      {indent}private final {enum_type} value;\n\n\
+      {indent}{enum_name}({enum_type} value) {{\n\
+      {indent}{indent}this.value = value;\n\
+      {indent}}}\n\n\
+      {indent}@{json_value}\n\
+      {indent}public {enum_type} getValue() {{\n\
+      {indent}{indent}return value;\n\
+      {indent}}}\n\n\
+      {indent}@{json_creator}\n\
+      {indent}public static {enum_name} fromValue({enum_type} value) {{\n\
+      {indent}{indent}for ({enum_name} entry : values()) {{\n\
+      {indent}{indent}{indent}if (entry.value.equals(value)) {{\n\
+      {indent}{indent}{indent}{indent}return entry;\n\
+      {indent}{indent}{indent}}}\n\
+      {indent}{indent}}}\n\
+      {indent}{indent}throw new IllegalArgumentException(\"Unknown {enum_name} value: \" + value);\n\
+      {indent}}}\n\
+      }}\n"
+    )
+  }
+}
@@ -0,0 +1,13 @@
+pub mod java_class_type_trait;
+pub mod java_enum_display_type_trait;
+pub mod java_fn_return_type_trait;
+pub mod java_identifier;
+pub mod java_imports;
+pub mod java_is_interface_type_trait;
+pub mod java_style;
+pub mod java_transformer;
+pub mod java_type_trait;
+
+#[cfg(test)]
+#[path = "./java_test.rs"]
+mod java_test;
@@ -0,0 +1,460 @@
+#[cfg(test)]
+mod tests {
+
+  use oxc_allocator::Allocator;
+  use oxc_parser::{ParseOptions, Parser};
+  use oxc_span::SourceType;
+
+  use crate::{
+    config::transform_config::TransformConfig,
+    languages::{
+      json_schema::json_schema_transformer::JsonSchemaTransformer,
+      shared::transform_context::TransformContext,
+    },
+  };
+
+  fn transform(source_text: &str, config: &TransformConfig) -> String {
+    transform_with_diagnostics(source_text, config).0
+  }
+
+  fn transform_with_diagnostics(
+    source_text: &str,
+    config: &TransformConfig,
+  ) -> (String, Vec<String>) {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, source_text, source_type)
+      .with_options(ParseOptions {
+        ..ParseOptions::default()
+      })
+      .parse();
+
+    let program = parser_ret.program;
+    let ctx = TransformContext::new(&program, config);
+    let transformed_code = JsonSchemaTransformer::transform(&program, &ctx);
+    (transformed_code, ctx.take_diagnostics())
+  }
+
+  #[test]
+  fn it_converts_to_json_schema() {
+    let source_text = r#"
+    export interface User {
+      readonly name: string;
+      age?: number;
+      tags: string[];
+      scores: Record<string, number>;
+      currency: Currency;
+      getName(): string;
+    }
+
+    enum Currency {
+      USD = "usd",
+      EUR = "eur"
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+    println!("{transformed_code}");
+
+    let expected_code = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$defs": {
+    "User": {
+      "type": "object",
+      "properties": {
+        "name": {
+          "type": "string"
+        },
+        "age": {
+          "type": "number"
+        },
+        "tags": {
+          "type": "array",
+          "items": {
+            "type": "string"
+          }
+        },
+        "scores": {
+          "type": "object",
+          "additionalProperties": {
+            "type": "number"
+          }
+        },
+        "currency": {
+          "$ref": "#/$defs/Currency"
+        }
+      },
+      "required": [
+        "name",
+        "tags",
+        "scores",
+        "currency"
+      ]
+    },
+    "Currency": {
+      "type": "string",
+      "enum": [
+        "usd",
+        "eur"
+      ]
+    }
+  }
+}
+"##;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_keeps_quoted_keys_and_reports_computed_keys() {
+    let source_text = r#"
+    const field = "id";
+
+    interface Headers {
+      readonly "content-type": string;
+      readonly "x-request-id"?: string;
+      readonly [field]: string;
+    }
+    "#;
+    let (transformed_code, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    let expected_code = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$defs": {
+    "Headers": {
+      "type": "object",
+      "properties": {
+        "content-type": {
+          "type": "string"
+        },
+        "x-request-id": {
+          "type": "string"
+        }
+      },
+      "required": [
+        "content-type"
+      ]
+    }
+  }
+}
+"##;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`Headers` has a computed key `field`, the property is left out"]
+    );
+  }
+
+  #[test]
+  fn it_only_references_declarations_of_the_same_file() {
+    let source_text = r#"
+    import { Address } from "./address";
+
+    interface User {
+      readonly id: UserId;
+      readonly address: Address;
+    }
+
+    type UserId = string;
+    "#;
+    let (transformed_code, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    let expected_code = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$defs": {
+    "User": {
+      "type": "object",
+      "properties": {
+        "id": {
+          "$ref": "#/$defs/UserId"
+        },
+        "address": {}
+      },
+      "required": [
+        "id",
+        "address"
+      ]
+    },
+    "UserId": {
+      "type": "string"
+    }
+  }
+}
+"##;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`Address` is not declared in this file, it is described as any value"]
+    );
+  }
+
+  #[test]
+  fn it_computes_enum_values() {
+    let source_text = r#"
+    enum Color {
+      Red = 5,
+      Green,
+    }
+
+    enum Offset {
+      Before = -1,
+      Same,
+      After,
+    }
+
+    enum Permission {
+      None,
+      Read = 1 << 0,
+      Write = 1 << 1,
+      ReadWrite = Read | Write,
+    }
+
+    enum Size {
+      Small = "small".length,
+    }
+    "#;
+    let (transformed_code, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    let expected_code = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$defs": {
+    "Color": {
+      "type": "integer",
+      "enum": [
+        5,
+        6
+      ]
+    },
+    "Offset": {
+      "type": "integer",
+      "enum": [
+        -1,
+        0,
+        1
+      ]
+    },
+    "Permission": {
+      "type": "integer",
+      "enum": [
+        0,
+        1,
+        2,
+        3
+      ]
+    },
+    "Size": {}
+  }
+}
+"##;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`Size` has members that are not constant, its cases are emitted without values"]
+    );
+  }
+
+  #[test]
+  fn it_converts_unions_literals_tuples_and_intersections() {
+    let source_text = r#"
+    interface Named {
+      readonly name: string;
+    }
+
+    interface Aged {
+      readonly age: number;
+    }
+
+    interface Shape {
+      readonly kind: "circle" | "square";
+      readonly sides: 4 | -1;
+      readonly label: string | null;
+      readonly closed: true;
+      readonly point: [number, number, string?];
+      readonly path: [string, ...number[]];
+      readonly owner: Named & Aged;
+      readonly onChange: () => void;
+    }
+
+    type Id = string | number;
+    "#;
+    let (transformed_code, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    let expected_code = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$defs": {
+    "Named": {
+      "type": "object",
+      "properties": {
+        "name": {
+          "type": "string"
+        }
+      },
+      "required": [
+        "name"
+      ]
+    },
+    "Aged": {
+      "type": "object",
+      "properties": {
+        "age": {
+          "type": "number"
+        }
+      },
+      "required": [
+        "age"
+      ]
+    },
+    "Shape": {
+      "type": "object",
+      "properties": {
+        "kind": {
+          "anyOf": [
+            {
+              "const": "circle"
+            },
+            {
+              "const": "square"
+            }
+          ]
+        },
+        "sides": {
+          "anyOf": [
+            {
+              "const": 4
+            },
+            {
+              "const": -1
+            }
+          ]
+        },
+        "label": {
+          "anyOf": [
+            {
+              "type": "string"
+            },
+            {
+              "type": "null"
+            }
+          ]
+        },
+        "closed": {
+          "const": true
+        },
+        "point": {
+          "type": "array",
+          "prefixItems": [
+            {
+              "type": "number"
+            },
+            {
+              "type": "number"
+            },
+            {
+              "type": "string"
+            }
+          ],
+          "items": false,
+          "minItems": 2
+        },
+        "path": {
+          "type": "array",
+          "prefixItems": [
+            {
+              "type": "string"
+            }
+          ],
+          "items": {
+            "type": "number"
+          },
+          "minItems": 1
+        },
+        "owner": {
+          "allOf": [
+            {
+              "$ref": "#/$defs/Named"
+            },
+            {
+              "$ref": "#/$defs/Aged"
+            }
+          ]
+        }
+      },
+      "required": [
+        "kind",
+        "sides",
+        "label",
+        "closed",
+        "point",
+        "path",
+        "owner"
+      ]
+    },
+    "Id": {
+      "anyOf": [
+        {
+          "type": "string"
+        },
+        {
+          "type": "number"
+        }
+      ]
+    }
+  }
+}
+"##;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(diagnostics, Vec::<String>::new());
+  }
+
+  #[test]
+  fn it_describes_index_signatures_as_additional_properties() {
+    let source_text = r#"
+    interface Labels {
+      readonly name: string;
+      readonly [key: string]: string;
+    }
+
+    interface Callbacks {
+      readonly [key: string]: () => void;
+    }
+    "#;
+    let (transformed_code, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    let expected_code = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$defs": {
+    "Labels": {
+      "type": "object",
+      "properties": {
+        "name": {
+          "type": "string"
+        }
+      },
+      "required": [
+        "name"
+      ],
+      "additionalProperties": {
+        "type": "string"
+      }
+    },
+    "Callbacks": {
+      "type": "object",
+      "properties": {},
+      "required": [],
+      "additionalProperties": {}
+    }
+  }
+}
+"##;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`() => void` has no JSON Schema, it is described as any value"]
+    );
+  }
+}
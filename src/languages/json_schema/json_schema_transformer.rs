@@ -0,0 +1,57 @@
+use oxc_ast::ast::{Declaration, Program, Statement};
+use serde_json::{Map, Value, json};
+
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::json_schema_type_trait::JsonSchemaType;
+
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub struct JsonSchemaTransformer;
+
+impl JsonSchemaTransformer {
+  /// Every declaration is added to `$defs`, so they can be referenced
+  /// as `<file>#/$defs/<Name>`
+  pub fn transform(ast_program: &Program, ctx: &TransformContext) -> String {
+    let mut defs = Map::new();
+
+    for statement in &ast_program.body {
+      let declaration = match statement {
+        Statement::ExportNamedDeclaration(export_decl) => export_decl.declaration.as_ref(),
+        _ => statement.as_declaration(),
+      };
+
+      match declaration {
+        Some(Declaration::TSInterfaceDeclaration(interface_decl)) => {
+          defs.insert(
+            interface_decl.id.name.to_string(),
+            interface_decl.to_json_schema_type(ctx),
+          );
+        }
+        Some(Declaration::TSEnumDeclaration(enum_decl)) => {
+          defs.insert(
+            enum_decl.id.name.to_string(),
+            enum_decl.to_json_schema_type(ctx),
+          );
+        }
+        Some(Declaration::TSTypeAliasDeclaration(alias_decl)) => {
+          defs.insert(
+            alias_decl.id.name.to_string(),
+            alias_decl.type_annotation.to_json_schema_type(ctx),
+          );
+        }
+        _ => {}
+      }
+    }
+
+    let schema = json!({
+      "$schema": JSON_SCHEMA_DIALECT,
+      "$defs": Value::Object(defs),
+    });
+
+    format!(
+      "{}\n",
+      serde_json::to_string_pretty(&schema).expect("Unable to serialize JSON Schema")
+    )
+  }
+}
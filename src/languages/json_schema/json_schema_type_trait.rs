@@ -0,0 +1,246 @@
+use oxc_ast::ast::{
+  Expression, TSEnumDeclaration, TSInterfaceDeclaration, TSLiteral, TSSignature, TSTupleType,
+  TSType, TSTypeReference, UnaryOperator,
+};
+use oxc_span::GetSpan;
+use serde_json::{Map, Value, json};
+
+use crate::languages::shared::{
+  enum_values::{EnumValue, get_enum_values_or_report},
+  transform_context::TransformContext,
+  tuple_elements::get_tuple_elements,
+};
+
+pub trait JsonSchemaType {
+  fn to_json_schema_type(&self, ctx: &TransformContext) -> Value;
+}
+
+impl JsonSchemaType for TSTypeReference<'_> {
+  fn to_json_schema_type(&self, ctx: &TransformContext) -> Value {
+    let type_name = self.type_name.to_string();
+    let type_param = |index: usize| {
+      self
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.get(index))
+        .map(|x| x.to_json_schema_type(ctx))
+        .unwrap_or_else(|| json!({}))
+    };
+
+    match type_name.as_str() {
+      "Promise" => type_param(0),
+      "Array" | "ReadonlyArray" => json!({ "type": "array", "items": type_param(0) }),
+      "Set" | "ReadonlySet" => {
+        json!({ "type": "array", "items": type_param(0), "uniqueItems": true })
+      }
+      "Record" | "Map" | "ReadonlyMap" => {
+        json!({ "type": "object", "additionalProperties": type_param(1) })
+      }
+      _ if ctx.is_declared(&type_name) => json!({ "$ref": format!("#/$defs/{type_name}") }),
+      _ => {
+        ctx.report(format!(
+          "`{type_name}` is not declared in this file, it is described as any value"
+        ));
+        json!({})
+      }
+    }
+  }
+}
+
+impl JsonSchemaType for TSType<'_> {
+  fn to_json_schema_type(&self, ctx: &TransformContext) -> Value {
+    match self {
+      TSType::TSStringKeyword(_) => json!({ "type": "string" }),
+      TSType::TSNumberKeyword(_) => json!({ "type": "number" }),
+      TSType::TSBooleanKeyword(_) => json!({ "type": "boolean" }),
+      TSType::TSNullKeyword(_) => json!({ "type": "null" }),
+      TSType::TSObjectKeyword(_) => json!({ "type": "object" }),
+      TSType::TSTypeReference(ref_type) => ref_type.to_json_schema_type(ctx),
+      TSType::TSArrayType(array_type) => {
+        json!({ "type": "array", "items": array_type.element_type.to_json_schema_type(ctx) })
+      }
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_json_schema_type(ctx),
+      TSType::TSParenthesizedType(paren_type) => {
+        paren_type.type_annotation.to_json_schema_type(ctx)
+      }
+      TSType::TSAnyKeyword(_) | TSType::TSUnknownKeyword(_) => json!({}),
+      TSType::TSUnionType(union_type) => json!({
+        "anyOf": union_type
+          .types
+          .iter()
+          .map(|x| x.to_json_schema_type(ctx))
+          .collect::<Vec<_>>()
+      }),
+      TSType::TSIntersectionType(intersection_type) => json!({
+        "allOf": intersection_type
+          .types
+          .iter()
+          .map(|x| x.to_json_schema_type(ctx))
+          .collect::<Vec<_>>()
+      }),
+      TSType::TSTupleType(tuple_type) => tuple_type.to_json_schema_type(ctx),
+      TSType::TSLiteralType(literal_type) => match &literal_type.literal {
+        TSLiteral::StringLiteral(literal) => json!({ "const": literal.value.as_str() }),
+        TSLiteral::NumericLiteral(literal) => json!({ "const": to_json_number(literal.value) }),
+        TSLiteral::BooleanLiteral(literal) => json!({ "const": literal.value }),
+        TSLiteral::NullLiteral(_) => json!({ "type": "null" }),
+        TSLiteral::UnaryExpression(unary)
+          if unary.operator == UnaryOperator::UnaryNegation
+            && let Expression::NumericLiteral(literal) = &unary.argument =>
+        {
+          json!({ "const": to_json_number(-literal.value) })
+        }
+        _ => report_unsupported_type(ctx, self),
+      },
+      _ => report_unsupported_type(ctx, self),
+    }
+  }
+}
+
+impl JsonSchemaType for TSTupleType<'_> {
+  /// Elements are described by position, a rest element describes the items after them
+  fn to_json_schema_type(&self, ctx: &TransformContext) -> Value {
+    let elements = get_tuple_elements(self);
+    let mut prefix_items = vec![];
+    let mut rest_items = json!(false);
+    let mut min_items = 0;
+
+    for element in &elements {
+      if element.rest {
+        rest_items = match element.element_type {
+          TSType::TSArrayType(array_type) => array_type.element_type.to_json_schema_type(ctx),
+          element_type => match element_type.to_json_schema_type(ctx) {
+            Value::Object(mut schema) => schema.remove("items").unwrap_or_else(|| json!({})),
+            _ => json!({}),
+          },
+        };
+        continue;
+      }
+
+      if !element.optional {
+        min_items = prefix_items.len() + 1;
+      }
+      prefix_items.push(element.element_type.to_json_schema_type(ctx));
+    }
+
+    json!({
+      "type": "array",
+      "prefixItems": prefix_items,
+      "items": rest_items,
+      "minItems": min_items,
+    })
+  }
+}
+
+/// A type JSON has no shape for, such as a function, is described as any value
+fn report_unsupported_type(ctx: &TransformContext, ts_type: &TSType) -> Value {
+  ctx.report(format!(
+    "`{}` has no JSON Schema, it is described as any value",
+    ctx.source_text(ts_type.span())
+  ));
+  json!({})
+}
+
+/// Integral numbers as JSON integers, `1` rather than `1.0`
+fn to_json_number(value: f64) -> Value {
+  if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+    json!(value as i64)
+  } else {
+    json!(value)
+  }
+}
+
+impl JsonSchemaType for TSInterfaceDeclaration<'_> {
+  /// Only data properties are described, methods and function properties have no JSON shape
+  fn to_json_schema_type(&self, ctx: &TransformContext) -> Value {
+    let mut properties = Map::new();
+    let mut required = vec![];
+    let mut additional_properties = None;
+
+    for signature in &self.body.body {
+      let prop_sig = match signature {
+        TSSignature::TSPropertySignature(prop_sig) => prop_sig,
+        // keys that are not declared hold the value of the index signature
+        TSSignature::TSIndexSignature(index_sig) => {
+          additional_properties = Some(
+            index_sig
+              .type_annotation
+              .type_annotation
+              .to_json_schema_type(ctx),
+          );
+          continue;
+        }
+        _ => continue,
+      };
+
+      // quoted keys are kept as they are, computed keys are only known at runtime
+      let Some(prop_name) = prop_sig.key.static_name() else {
+        ctx.report(format!(
+          "`{}` has a computed key `{}`, the property is left out",
+          self.id.name,
+          ctx.source_text(prop_sig.key.span())
+        ));
+        continue;
+      };
+      let prop_name = prop_name.to_string();
+
+      let type_annotation = prop_sig
+        .type_annotation
+        .as_ref()
+        .map(|annotation| &annotation.type_annotation);
+
+      if matches!(type_annotation, Some(TSType::TSFunctionType(_))) {
+        continue;
+      }
+
+      if !prop_sig.optional {
+        required.push(Value::String(prop_name.clone()));
+      }
+
+      properties.insert(
+        prop_name,
+        type_annotation
+          .map(|x| x.to_json_schema_type(ctx))
+          .unwrap_or_else(|| json!({})),
+      );
+    }
+
+    let mut schema = json!({
+      "type": "object",
+      "properties": Value::Object(properties),
+      "required": Value::Array(required),
+    });
+    if let Some(additional_properties) = additional_properties {
+      schema["additionalProperties"] = additional_properties;
+    }
+    schema
+  }
+}
+
+impl JsonSchemaType for TSEnumDeclaration<'_> {
+  fn to_json_schema_type(&self, ctx: &TransformContext) -> Value {
+    let Some(values) = get_enum_values_or_report(ctx, self, &self.id.name) else {
+      return json!({});
+    };
+
+    let enum_values = values
+      .iter()
+      .map(|x| match x {
+        EnumValue::String(value) => json!(value),
+        EnumValue::Number(value) => to_json_number(*value),
+      })
+      .collect::<Vec<_>>();
+
+    let enum_type = if enum_values.iter().all(|x| x.is_string()) {
+      "string"
+    } else if enum_values.iter().all(|x| x.is_i64() || x.is_u64()) {
+      "integer"
+    } else if enum_values.iter().all(|x| x.is_number()) {
+      "number"
+    } else {
+      return json!({ "enum": enum_values });
+    };
+
+    json!({ "type": enum_type, "enum": enum_values })
+  }
+}
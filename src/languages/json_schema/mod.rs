@@ -0,0 +1,6 @@
+pub mod json_schema_transformer;
+pub mod json_schema_type_trait;
+
+#[cfg(test)]
+#[path = "./json_schema_test.rs"]
+mod json_schema_test;
@@ -0,0 +1,59 @@
+use crate::languages::{
+  kotlin::{kotlin_imports::import_kotlin_type, kotlin_style},
+  shared::transform_context::TransformContext,
+};
+
+/// Annotations of a data class serialized by its additional properties serializer
+pub fn get_kotlin_additional_properties_annotations(
+  ctx: &TransformContext,
+  class_name: &str,
+) -> String {
+  let experimental_api = import_kotlin_type(ctx, "ExperimentalSerializationApi");
+  let keep_generated_serializer = import_kotlin_type(ctx, "KeepGeneratedSerializer");
+  let serializable = import_kotlin_type(ctx, "Serializable");
+  format!(
+    "@OptIn({experimental_api}::class)\n\
+     @{keep_generated_serializer}\n\
+     @{serializable}(with = {class_name}Serializer::class)\n"
+  )
+}
+
+/// Serializer that moves the keys a data class does not declare into its
+/// `additionalProperties` map and back
+pub fn get_kotlin_additional_properties_serializer(
+  ctx: &TransformContext,
+  class_name: &str,
+  property_names: &[String],
+  visibility: &str,
+) -> String {
+  let indent = kotlin_style::INDENT_SPACE;
+  let indent2 = indent.repeat(2);
+  let known_keys = property_names
+    .iter()
+    .map(|name| format!("\"{name}\""))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let transforming_serializer = import_kotlin_type(ctx, "JsonTransformingSerializer");
+  let json_element = import_kotlin_type(ctx, "JsonElement");
+  let json_object = import_kotlin_type(ctx, "JsonObject");
+
+  format!(
+    "{visibility}object {class_name}Serializer : {transforming_serializer}<{class_name}>({class_name}.generatedSerializer()) {{
+{indent}private val knownKeys = setOf({known_keys})
+
+{indent}override fun transformDeserialize(element: {json_element}): {json_element} {{
+{indent2}val properties = element as {json_object}
+{indent2}val additionalProperties = {json_object}(properties.filterKeys {{ it !in knownKeys }})
+{indent2}return {json_object}(properties.filterKeys {{ it in knownKeys }} + (\"additionalProperties\" to additionalProperties))
+{indent}}}
+
+{indent}override fun transformSerialize(element: {json_element}): {json_element} {{
+{indent2}val properties = element as {json_object}
+{indent2}val additionalProperties = properties[\"additionalProperties\"] as? {json_object} ?: {json_object}(emptyMap())
+{indent2}return {json_object}(properties - \"additionalProperties\" + additionalProperties)
+{indent}}}
+}}
+
+"
+  )
+}
@@ -0,0 +1,58 @@
+use crate::{
+  config::transform_config::KotlinAsyncStyle,
+  languages::{
+    kotlin::kotlin_imports::import_kotlin_type, shared::transform_context::TransformContext,
+  },
+};
+
+/// Modifier, params and result of a function resolving to a value
+pub struct KotlinAsyncSignature {
+  /// `suspend ` or empty
+  pub modifier: &'static str,
+  pub params: String,
+  pub return_type: String,
+}
+
+/// Signature of a function resolving to `result_type` in the configured async style
+pub fn get_kotlin_async_signature(
+  ctx: &TransformContext,
+  params: String,
+  result_type: &str,
+) -> KotlinAsyncSignature {
+  match ctx.config.kotlin.async_style {
+    KotlinAsyncStyle::Suspend => KotlinAsyncSignature {
+      modifier: "suspend ",
+      params,
+      return_type: result_type.to_string(),
+    },
+    KotlinAsyncStyle::Callback => {
+      let separator = if params.is_empty() { "" } else { ", " };
+      KotlinAsyncSignature {
+        modifier: "",
+        params: format!("{params}{separator}callback: (Result<{result_type}>) -> Unit"),
+        return_type: "Unit".to_string(),
+      }
+    }
+    _ => KotlinAsyncSignature {
+      modifier: "",
+      params,
+      return_type: to_kotlin_async_type(ctx, result_type),
+    },
+  }
+}
+
+/// Type of a value resolving to `result_type`, such as a `Promise` property
+/// that cannot be a `suspend` function
+pub fn to_kotlin_async_type(ctx: &TransformContext, result_type: &str) -> String {
+  match ctx.config.kotlin.async_style {
+    KotlinAsyncStyle::Suspend | KotlinAsyncStyle::Deferred => {
+      format!("{}<{result_type}>", import_kotlin_type(ctx, "Deferred"))
+    }
+    KotlinAsyncStyle::Callback => format!("((Result<{result_type}>) -> Unit) -> Unit"),
+    KotlinAsyncStyle::CompletableFuture => format!(
+      "{}<{result_type}>",
+      import_kotlin_type(ctx, "CompletableFuture")
+    ),
+    KotlinAsyncStyle::RxSingle => format!("{}<{result_type}>", import_kotlin_type(ctx, "Single")),
+  }
+}
@@ -0,0 +1,89 @@
+use oxc_span::Span;
+
+use crate::languages::shared::{jsdoc::JsDoc, transform_context::TransformContext};
+
+pub trait KotlinDocComment {
+  /// A `/** */` KDoc block ending with a new line
+  fn to_kotlin_doc_comment(&self, indent: &str) -> String;
+
+  /// Annotation lines such as `@Deprecated("...")`, each one ending with a new line
+  fn to_kotlin_annotations(&self, indent: &str) -> String;
+}
+
+impl KotlinDocComment for JsDoc {
+  fn to_kotlin_doc_comment(&self, indent: &str) -> String {
+    let mut lines = self
+      .description
+      .lines()
+      .map(|x| x.to_string())
+      .collect::<Vec<_>>();
+
+    let mut tag_lines: Vec<String> = vec![];
+    for tag in &self.tags {
+      let block_tag = match tag.tag_name.as_str() {
+        "param" => {
+          let (param_name, description) = tag.to_param_parts();
+          format!("@param {param_name} {description}")
+        }
+        "returns" | "return" => format!("@return {}", tag.text),
+        "throws" | "exception" => format!("@throws {}", tag.text),
+        "see" => format!("@see {}", tag.text),
+        "example" => {
+          tag_lines.push("```".to_string());
+          tag_lines.extend(tag.text.lines().map(|x| x.to_string()));
+          tag_lines.push("```".to_string());
+          continue;
+        }
+        _ => continue,
+      };
+      tag_lines.extend(block_tag.trim_end().lines().map(|x| x.to_string()));
+    }
+
+    if !lines.is_empty() && !tag_lines.is_empty() {
+      lines.push("".to_string());
+    }
+    lines.extend(tag_lines);
+
+    if lines.is_empty() {
+      return "".to_string();
+    }
+
+    let body = lines
+      .iter()
+      .map(|x| {
+        if x.is_empty() {
+          format!("{indent} *\n")
+        } else {
+          format!("{indent} * {x}\n")
+        }
+      })
+      .collect::<String>();
+
+    format!("{indent}/**\n{body}{indent} */\n")
+  }
+
+  fn to_kotlin_annotations(&self, indent: &str) -> String {
+    self
+      .tag("deprecated")
+      .map(|tag| {
+        // `$` starts a string template in Kotlin
+        let message = tag.to_string_literal_text().replace('$', "\\$");
+        format!("{indent}@Deprecated(\"{message}\")\n")
+      })
+      .unwrap_or_default()
+  }
+}
+
+/// KDoc and annotations of the node at `span`, empty when it has no JSDoc
+pub fn get_kotlin_doc_comment(ctx: &TransformContext, span: Span, indent: &str) -> String {
+  ctx
+    .jsdoc(span)
+    .map(|jsdoc| {
+      format!(
+        "{}{}",
+        jsdoc.to_kotlin_doc_comment(indent),
+        jsdoc.to_kotlin_annotations(indent)
+      )
+    })
+    .unwrap_or_default()
+}
@@ -1,6 +1,6 @@
-use oxc_ast::ast::{Expression, TSEnumDeclaration};
+use oxc_ast::ast::TSEnumDeclaration;
 
-use crate::languages::shared::enum_trait::GetEnumDisplayValue;
+use crate::languages::shared::enum_values::{EnumValue, EnumValues};
 
 pub trait KotlinEnumDisplayType {
   fn to_kotlin_enum_display_type(&self) -> String;
@@ -8,29 +8,42 @@ pub trait KotlinEnumDisplayType {
 
 impl KotlinEnumDisplayType for TSEnumDeclaration<'_> {
   fn to_kotlin_enum_display_type(&self) -> String {
-    let all_enum_string = self
-      .members
+    let values = self.get_enum_values();
+    let all_enum_string = values
       .iter()
-      .all(|x| matches!(x.initializer, Some(Expression::StringLiteral(_))));
+      .all(|x| matches!(x, Some(EnumValue::String(_))));
 
     if all_enum_string {
       return "String".to_string();
     }
 
-    let all_enum_double = self.members.iter().all(|x| {
-      matches!(x.initializer, Some(Expression::NumericLiteral(_)))
-        && x
-          .initializer
-          .as_ref()
-          .expect("Unable to get enum initializer value for get_enum_display_type")
-          .get_enum_display_value()
-          .contains(".")
-    });
-
-    if all_enum_double {
+    let numbers = values
+      .iter()
+      .filter_map(|x| match x {
+        Some(EnumValue::Number(value)) => Some(*value),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    if numbers.iter().any(|value| value.fract() != 0.0) {
       return "Double".to_string();
     }
 
-    "Int".to_string()
+    let is_int = |value: &f64| (f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(value);
+    if numbers.iter().all(is_int) {
+      "Int".to_string()
+    } else {
+      "Long".to_string()
+    }
+  }
+}
+
+/// The value as an argument of an enum entry whose `value` is `enum_type`
+pub fn to_kotlin_enum_literal(value: &EnumValue, enum_type: &str) -> String {
+  match (value, enum_type) {
+    // a `Double` or `Long` parameter does not take an `Int` literal
+    (EnumValue::Number(value), "Double") => format!("{value:?}"),
+    (EnumValue::Number(value), "Long") => format!("{value}L"),
+    (value, _) => value.to_literal().replace('$', "\\$"),
   }
 }
@@ -0,0 +1,75 @@
+use crate::languages::{
+  kotlin::{kotlin_imports::import_kotlin_type, kotlin_style},
+  shared::transform_context::TransformContext,
+};
+
+/// Serializer of an enum coded by its `value` and looked up with `fromValue`,
+/// values the enum does not declare decode to `UNKNOWN` when `has_unknown_case`. `enum_type` is the
+/// type of `value`, `JsonPrimitive` for enums mixing strings and numbers
+pub fn get_kotlin_enum_serializer(
+  ctx: &TransformContext,
+  enum_name: &str,
+  enum_type: &str,
+  has_unknown_case: bool,
+  visibility: &str,
+) -> String {
+  let indent = kotlin_style::INDENT_SPACE;
+  let indent2 = indent.repeat(2);
+  let indent3 = indent.repeat(3);
+
+  let (descriptor, encode, decode) = match enum_type {
+    "JsonPrimitive" => {
+      let json_primitive = import_kotlin_type(ctx, "JsonPrimitive");
+      (
+        format!("{json_primitive}.serializer().descriptor"),
+        format!("encoder.encodeSerializableValue({json_primitive}.serializer(), value.value)"),
+        format!("decoder.decodeSerializableValue({json_primitive}.serializer())"),
+      )
+    }
+    _ => {
+      let primitive_kind = match enum_type {
+        "String" => "STRING",
+        "Double" => "DOUBLE",
+        "Long" => "LONG",
+        _ => "INT",
+      };
+      (
+        format!(
+          "{}(\"{enum_name}\", {}.{primitive_kind})",
+          import_kotlin_type(ctx, "PrimitiveSerialDescriptor"),
+          import_kotlin_type(ctx, "PrimitiveKind")
+        ),
+        format!("encoder.encode{enum_type}(value.value)"),
+        format!("decoder.decode{enum_type}()"),
+      )
+    }
+  };
+
+  let fallback = if has_unknown_case {
+    "".to_string()
+  } else {
+    format!(
+      "\n{indent3}?: throw {}(\"Unknown {enum_name} value $value\")",
+      import_kotlin_type(ctx, "SerializationException")
+    )
+  };
+
+  let serializer = import_kotlin_type(ctx, "KSerializer");
+  let encoder = import_kotlin_type(ctx, "Encoder");
+  let decoder = import_kotlin_type(ctx, "Decoder");
+
+  format!(
+    "{visibility}object {enum_name}Serializer : {serializer}<{enum_name}> {{
+{indent}override val descriptor = {descriptor}
+
+{indent}override fun serialize(encoder: {encoder}, value: {enum_name}) =
+{indent2}{encode}
+
+{indent}override fun deserialize(decoder: {decoder}): {enum_name} {{
+{indent2}val value = {decode}
+{indent2}return {enum_name}.fromValue(value){fallback}
+{indent}}}
+}}
+"
+  )
+}
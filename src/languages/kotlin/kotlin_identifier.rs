@@ -0,0 +1,61 @@
+use oxc_ast::ast::PropertyKey;
+
+use crate::{
+  config::transform_config::NamingConvention,
+  languages::{
+    kotlin::kotlin_type_trait::KotlinType,
+    shared::{
+      identifier::{escape_reserved_word, to_valid_identifier},
+      transform_context::TransformContext,
+    },
+  },
+};
+
+/// Hard keywords that need backticks to be used as a name
+const KOTLIN_RESERVED_WORDS: &[&str] = &[
+  "as",
+  "break",
+  "class",
+  "continue",
+  "do",
+  "else",
+  "false",
+  "for",
+  "fun",
+  "if",
+  "in",
+  "interface",
+  "is",
+  "null",
+  "object",
+  "package",
+  "return",
+  "super",
+  "this",
+  "throw",
+  "true",
+  "try",
+  "typealias",
+  "typeof",
+  "val",
+  "var",
+  "when",
+  "while",
+];
+
+/// A TS name as a Kotlin identifier, sanitized and escaped, `in` -> `` `in` ``
+pub fn to_kotlin_identifier(name: &str) -> String {
+  escape_reserved_word(&to_valid_identifier(name), KOTLIN_RESERVED_WORDS)
+}
+
+/// The name of a property or method key in the configured naming `convention`
+pub fn to_kotlin_member_name(
+  ctx: &TransformContext,
+  key: &PropertyKey,
+  convention: Option<NamingConvention>,
+) -> String {
+  match (key.static_name(), convention) {
+    (Some(name), Some(convention)) => to_kotlin_identifier(&convention.apply(&name)),
+    _ => key.to_kotlin_type(ctx),
+  }
+}
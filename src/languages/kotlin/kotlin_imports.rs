@@ -0,0 +1,58 @@
+use crate::languages::shared::transform_context::TransformContext;
+
+/// Built-in types that need an import, in the order they are emitted
+pub const KOTLIN_IMPORTS: [(&str, &str); 22] = [
+  ("Single", "io.reactivex.rxjava3.core.Single"),
+  ("BigInteger", "java.math.BigInteger"),
+  ("URI", "java.net.URI"),
+  ("Instant", "java.time.Instant"),
+  (
+    "CompletableFuture",
+    "java.util.concurrent.CompletableFuture",
+  ),
+  ("Deferred", "kotlinx.coroutines.Deferred"),
+  ("Flow", "kotlinx.coroutines.flow.Flow"),
+  (
+    "ExperimentalSerializationApi",
+    "kotlinx.serialization.ExperimentalSerializationApi",
+  ),
+  ("KSerializer", "kotlinx.serialization.KSerializer"),
+  (
+    "KeepGeneratedSerializer",
+    "kotlinx.serialization.KeepGeneratedSerializer",
+  ),
+  ("SerialName", "kotlinx.serialization.SerialName"),
+  ("Serializable", "kotlinx.serialization.Serializable"),
+  (
+    "SerializationException",
+    "kotlinx.serialization.SerializationException",
+  ),
+  (
+    "PrimitiveKind",
+    "kotlinx.serialization.descriptors.PrimitiveKind",
+  ),
+  (
+    "PrimitiveSerialDescriptor",
+    "kotlinx.serialization.descriptors.PrimitiveSerialDescriptor",
+  ),
+  ("Decoder", "kotlinx.serialization.encoding.Decoder"),
+  ("Encoder", "kotlinx.serialization.encoding.Encoder"),
+  ("JsonElement", "kotlinx.serialization.json.JsonElement"),
+  ("JsonNull", "kotlinx.serialization.json.JsonNull"),
+  ("JsonObject", "kotlinx.serialization.json.JsonObject"),
+  ("JsonPrimitive", "kotlinx.serialization.json.JsonPrimitive"),
+  (
+    "JsonTransformingSerializer",
+    "kotlinx.serialization.json.JsonTransformingSerializer",
+  ),
+];
+
+/// How the emitted code refers to `type_name` of `KOTLIN_IMPORTS`, fully
+/// qualified when a declaration of the file has the same name
+pub fn import_kotlin_type(ctx: &TransformContext, type_name: &str) -> String {
+  let import_path = KOTLIN_IMPORTS
+    .iter()
+    .find(|(name, _)| *name == type_name)
+    .map_or(type_name, |(_, import_path)| import_path);
+  ctx.import_type(type_name, import_path)
+}
@@ -4,9 +4,9 @@ pub trait KotlinIsInterfaceType {
   fn is_kotlin_interface_type(&self) -> bool;
 }
 
-impl KotlinIsInterfaceType for TSInterfaceDeclaration<'_> {
+impl KotlinIsInterfaceType for TSSignature<'_> {
   fn is_kotlin_interface_type(&self) -> bool {
-    let is_interface = self.body.body.iter().any(|x| match x {
+    match self {
       TSSignature::TSMethodSignature(_) => true,
       TSSignature::TSPropertySignature(prop_sig) => {
         if let Some(type_annotation) = &prop_sig.type_annotation {
@@ -16,7 +16,12 @@ impl KotlinIsInterfaceType for TSInterfaceDeclaration<'_> {
         }
       }
       _ => false,
-    });
-    is_interface
+    }
+  }
+}
+
+impl KotlinIsInterfaceType for TSInterfaceDeclaration<'_> {
+  fn is_kotlin_interface_type(&self) -> bool {
+    self.body.body.iter().any(|x| x.is_kotlin_interface_type())
   }
 }
@@ -0,0 +1,1161 @@
+#[cfg(test)]
+mod tests {
+
+  use oxc_allocator::Allocator;
+  use oxc_parser::{ParseOptions, Parser};
+  use oxc_span::SourceType;
+
+  use crate::{
+    config::transform_config::{
+      KotlinAsyncStyle, KotlinBigIntType, KotlinVisibility, NamingConvention, NamingConventions,
+      TransformConfig,
+    },
+    languages::{
+      kotlin::kotlin_transformer::KotlinTransformer, shared::transform_context::TransformContext,
+    },
+  };
+
+  fn transform(source_text: &str, config: &TransformConfig) -> String {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, source_text, source_type)
+      .with_options(ParseOptions {
+        ..ParseOptions::default()
+      })
+      .parse();
+
+    let program = parser_ret.program;
+    KotlinTransformer::transform(&program, &TransformContext::new(&program, config))
+  }
+
+  #[test]
+  fn it_converts_jsdoc_to_kdoc() {
+    let source_text = r#"
+    /** A registered user */
+    export interface User {
+      /**
+       * Display name
+       * @example
+       * "Jane"
+       */
+      readonly name: string;
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+    println!("{transformed_code}");
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+/**
+ * A registered user
+ */
+@Serializable
+data class User (
+    /**
+     * Display name
+     *
+     * ```
+     * "Jane"
+     * ```
+     */
+    val name: String
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_deprecated_tags_to_annotations() {
+    let source_text = r#"
+    enum Currency {
+      /**
+       * Old euro code
+       * @deprecated use EUR
+       */
+      EURO = "euro",
+      EUR = "eur"
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+@Serializable
+enum class Currency(val value: String) {
+    /**
+     * Old euro code
+     */
+    @Deprecated("use EUR")
+    @SerialName("euro") EURO("euro"),
+    @SerialName("eur") EUR("eur");
+
+    companion object {
+        fun fromValue(value: String): Currency? = entries.firstOrNull { it.value == value }
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_jsdoc_native_overrides() {
+    let source_text = r#"
+    /** @kotlin-name Account */
+    interface User {
+      /** @native-type Int */
+      readonly age: number;
+      /** @kotlin-type Long */
+      readonly createdAt: number;
+      /** @native-ignore */
+      readonly internalFlag: boolean;
+    }
+
+    interface Session {
+      readonly user: User;
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+@Serializable
+data class Account (
+    val age: Int,
+    val createdAt: Long
+)
+
+@Serializable
+data class Session (
+    val user: Account
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_well_known_types() {
+    let source_text = r#"
+    interface Attachment {
+      readonly createdAt: Date;
+      readonly bytes: ArrayBuffer;
+      readonly size: bigint;
+      readonly link: URL;
+      readonly failure: Error;
+      readonly metadata: any;
+    }
+    "#;
+
+    let mut config = TransformConfig::default();
+    config.kotlin.bigint_type = KotlinBigIntType::BigInteger;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import java.math.BigInteger
+import java.net.URI
+import java.time.Instant
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonElement
+
+@Serializable
+data class Attachment (
+    val createdAt: Instant,
+    val bytes: ByteArray,
+    val size: BigInteger,
+    val link: URI,
+    val failure: Throwable,
+    val metadata: JsonElement
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_imports_only_the_types_the_code_refers_to() {
+    let source_text = r#"
+    /** Single sign-on settings, see the Flow diagram */
+    interface Settings {
+      readonly issuedAt: Date;
+    }
+
+    interface Instant {
+      readonly seconds: number;
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+/**
+ * Single sign-on settings, see the Flow diagram
+ */
+@Serializable
+data class Settings (
+    val issuedAt: java.time.Instant
+)
+
+@Serializable
+data class Instant (
+    val seconds: Double
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_infers_integer_numbers() {
+    let source_text = r#"
+    export type Long = number;
+
+    interface Message {
+      readonly sentAt: Long;
+      /** @int */
+      readonly retries: number;
+      readonly score: number;
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+@Serializable
+data class Message (
+    val sentAt: Long,
+    val retries: Int,
+    val score: Double
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_matches_name_patterns_against_properties_and_params_only() {
+    let source_text = r#"
+    interface Catalog {
+      readonly itemCount: number;
+      itemCount(shelfId: number): number;
+      /** @int */
+      pageCount(): number;
+    }
+    "#;
+    let config = TransformConfig {
+      integer_name_patterns: vec!["*Count".to_string(), "*Id".to_string()],
+      ..TransformConfig::default()
+    };
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"
+interface Catalog {
+    val itemCount: Int
+    fun itemCount(shelfId: Int): Double
+    fun pageCount(): Int
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_branded_types_to_value_classes() {
+    let source_text = r#"
+    type UserId = string & { readonly __brand: "UserId" };
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+@Serializable
+@JvmInline
+value class UserId(val value: String)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_merges_intersection_types() {
+    let source_text = r#"
+    interface User {
+      readonly name: string;
+    }
+
+    type AdminUser = User & { permissions: string[] };
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+@Serializable
+data class User (
+    val name: String
+)
+
+@Serializable
+data class AdminUser (
+    val name: String,
+    var permissions: List<String>
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_tuple_types() {
+    let source_text = r#"
+    type Point = [x: number, y: number];
+
+    interface Order {
+      readonly range: [number, number];
+      readonly size: [number, number, number];
+      readonly window: [string, number, number, boolean?];
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+@Serializable
+data class Point (
+    val x: Double,
+    val y: Double
+)
+
+@Serializable
+data class Order (
+    val range: Pair<Double, Double>,
+    val size: Triple<Double, Double, Double>,
+    val window: OrderWindow
+)
+
+@Serializable
+data class OrderWindow (
+    val item1: String,
+    val item2: Double,
+    val item3: Double,
+    val item4: Boolean? = null
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_index_signatures() {
+    let source_text = r#"
+    interface Headers {
+      [key: string]: string;
+    }
+
+    export interface Metadata {
+      readonly version: number;
+      readonly label?: string;
+      readonly [key: string]: number;
+    }
+
+    interface Scores {
+      readonly byIndex: { [index: number]: boolean };
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.KeepGeneratedSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonElement
+import kotlinx.serialization.json.JsonObject
+import kotlinx.serialization.json.JsonTransformingSerializer
+
+typealias Headers = Map<String, String>
+
+@OptIn(ExperimentalSerializationApi::class)
+@KeepGeneratedSerializer
+@Serializable(with = MetadataSerializer::class)
+data class Metadata (
+    val version: Double,
+    val label: String,
+    val additionalProperties: Map<String, Double> = emptyMap()
+)
+
+object MetadataSerializer : JsonTransformingSerializer<Metadata>(Metadata.generatedSerializer()) {
+    private val knownKeys = setOf("version", "label")
+
+    override fun transformDeserialize(element: JsonElement): JsonElement {
+        val properties = element as JsonObject
+        val additionalProperties = JsonObject(properties.filterKeys { it !in knownKeys })
+        return JsonObject(properties.filterKeys { it in knownKeys } + ("additionalProperties" to additionalProperties))
+    }
+
+    override fun transformSerialize(element: JsonElement): JsonElement {
+        val properties = element as JsonObject
+        val additionalProperties = properties["additionalProperties"] as? JsonObject ?: JsonObject(emptyMap())
+        return JsonObject(properties - "additionalProperties" + additionalProperties)
+    }
+}
+
+@Serializable
+data class Scores (
+    val byIndex: Map<Int, Boolean>
+)
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_counts_up_implicit_enum_values_after_an_initializer() {
+    let source_text = r#"
+    enum Level {
+      Low = 5,
+      Medium,
+      High,
+      Off = -1,
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+@Serializable(with = LevelSerializer::class)
+enum class Level(val value: Int) {
+    Low(5),
+    Medium(6),
+    High(7),
+    Off(-1);
+
+    companion object {
+        fun fromValue(value: Int): Level? = entries.firstOrNull { it.value == value }
+    }
+}
+
+object LevelSerializer : KSerializer<Level> {
+    override val descriptor = PrimitiveSerialDescriptor("Level", PrimitiveKind.INT)
+
+    override fun serialize(encoder: Encoder, value: Level) =
+        encoder.encodeInt(value.value)
+
+    override fun deserialize(decoder: Decoder): Level {
+        val value = decoder.decodeInt()
+        return Level.fromValue(value)
+            ?: throw SerializationException("Unknown Level value $value")
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_evaluates_constant_enum_expressions() {
+    let source_text = r#"
+    enum Flags {
+      None = 0,
+      Read = 1 << 0,
+      Write = 1 << 1,
+      ReadWrite = Read | Flags.Write,
+      Mask = ~0 >>> 28,
+    }
+
+    enum Ratio {
+      Half = 0.5,
+      Whole = Half * 2,
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+@Serializable(with = FlagsSerializer::class)
+enum class Flags(val value: Int) {
+    None(0),
+    Read(1),
+    Write(2),
+    ReadWrite(3),
+    Mask(15);
+
+    companion object {
+        fun fromValue(value: Int): Flags? = entries.firstOrNull { it.value == value }
+    }
+}
+
+object FlagsSerializer : KSerializer<Flags> {
+    override val descriptor = PrimitiveSerialDescriptor("Flags", PrimitiveKind.INT)
+
+    override fun serialize(encoder: Encoder, value: Flags) =
+        encoder.encodeInt(value.value)
+
+    override fun deserialize(decoder: Decoder): Flags {
+        val value = decoder.decodeInt()
+        return Flags.fromValue(value)
+            ?: throw SerializationException("Unknown Flags value $value")
+    }
+}
+@Serializable(with = RatioSerializer::class)
+enum class Ratio(val value: Double) {
+    Half(0.5),
+    Whole(1.0);
+
+    companion object {
+        fun fromValue(value: Double): Ratio? = entries.firstOrNull { it.value == value }
+    }
+}
+
+object RatioSerializer : KSerializer<Ratio> {
+    override val descriptor = PrimitiveSerialDescriptor("Ratio", PrimitiveKind.DOUBLE)
+
+    override fun serialize(encoder: Encoder, value: Ratio) =
+        encoder.encodeDouble(value.value)
+
+    override fun deserialize(decoder: Decoder): Ratio {
+        val value = decoder.decodeDouble()
+        return Ratio.fromValue(value)
+            ?: throw SerializationException("Unknown Ratio value $value")
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_emits_enums_that_are_not_constant_without_values() {
+    let source_text = r#"
+    enum Prefix {
+      Api = "api",
+      Users = `${"x"}`,
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.Serializable
+
+@Serializable
+enum class Prefix {
+    Api,
+    Users
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_flag_enums() {
+    let source_text = r#"
+    export enum Permissions {
+      /** Can read */
+      Read = 1 << 0,
+      Write = 1 << 1,
+      All = Read | Write,
+    }
+
+    /** @flags */
+    enum Style {
+      Plain,
+      Bold,
+    }
+
+    enum Level {
+      Low = 1,
+      Medium = 2,
+      High = 3,
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+@Serializable
+@JvmInline
+value class Permissions(val value: Int) {
+    infix fun or(other: Permissions) = Permissions(value or other.value)
+
+    operator fun contains(other: Permissions) = (value and other.value) == other.value
+
+    companion object {
+        /**
+         * Can read
+         */
+        val Read = Permissions(1)
+        val Write = Permissions(2)
+        val All = Permissions(3)
+    }
+}
+@Serializable
+@JvmInline
+value class Style(val value: Int) {
+    infix fun or(other: Style) = Style(value or other.value)
+
+    operator fun contains(other: Style) = (value and other.value) == other.value
+
+    companion object {
+        val Plain = Style(0)
+        val Bold = Style(1)
+    }
+}
+@Serializable(with = LevelSerializer::class)
+enum class Level(val value: Int) {
+    Low(1),
+    Medium(2),
+    High(3);
+
+    companion object {
+        fun fromValue(value: Int): Level? = entries.firstOrNull { it.value == value }
+    }
+}
+
+object LevelSerializer : KSerializer<Level> {
+    override val descriptor = PrimitiveSerialDescriptor("Level", PrimitiveKind.INT)
+
+    override fun serialize(encoder: Encoder, value: Level) =
+        encoder.encodeInt(value.value)
+
+    override fun deserialize(decoder: Decoder): Level {
+        val value = decoder.decodeInt()
+        return Level.fromValue(value)
+            ?: throw SerializationException("Unknown Level value $value")
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_mixed_enums() {
+    let source_text = r#"
+    enum Answer {
+      No = 0,
+      Yes = "yes",
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.JsonPrimitive
+
+@Serializable(with = AnswerSerializer::class)
+enum class Answer(val value: JsonPrimitive) {
+    No(JsonPrimitive(0)),
+    Yes(JsonPrimitive("yes"));
+
+    companion object {
+        fun fromValue(value: JsonPrimitive): Answer? = entries.firstOrNull { it.value == value }
+    }
+}
+
+object AnswerSerializer : KSerializer<Answer> {
+    override val descriptor = JsonPrimitive.serializer().descriptor
+
+    override fun serialize(encoder: Encoder, value: Answer) =
+        encoder.encodeSerializableValue(JsonPrimitive.serializer(), value.value)
+
+    override fun deserialize(decoder: Decoder): Answer {
+        val value = decoder.decodeSerializableValue(JsonPrimitive.serializer())
+        return Answer.fromValue(value)
+            ?: throw SerializationException("Unknown Answer value $value")
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_adds_unknown_cases_to_open_enums() {
+    let source_text = r#"
+    enum Currency {
+      Usd = "usd",
+      Eur = "eur",
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.kotlin.unknown_enum_case = true;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+@Serializable(with = CurrencySerializer::class)
+enum class Currency(val value: String) {
+    Usd("usd"),
+    Eur("eur"),
+    UNKNOWN("");
+
+    companion object {
+        fun fromValue(value: String): Currency =
+            entries.firstOrNull { it != UNKNOWN && it.value == value } ?: UNKNOWN
+    }
+}
+
+object CurrencySerializer : KSerializer<Currency> {
+    override val descriptor = PrimitiveSerialDescriptor("Currency", PrimitiveKind.STRING)
+
+    override fun serialize(encoder: Encoder, value: Currency) =
+        encoder.encodeString(value.value)
+
+    override fun deserialize(decoder: Decoder): Currency {
+        val value = decoder.decodeString()
+        return Currency.fromValue(value)
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_adds_unknown_cases_to_mixed_enums() {
+    let source_text = r#"
+    enum Answer {
+      No = 0,
+      Yes = "yes",
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.kotlin.unknown_enum_case = true;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.JsonNull
+import kotlinx.serialization.json.JsonPrimitive
+
+@Serializable(with = AnswerSerializer::class)
+enum class Answer(val value: JsonPrimitive) {
+    No(JsonPrimitive(0)),
+    Yes(JsonPrimitive("yes")),
+    UNKNOWN(JsonNull);
+
+    companion object {
+        fun fromValue(value: JsonPrimitive): Answer =
+            entries.firstOrNull { it != UNKNOWN && it.value == value } ?: UNKNOWN
+    }
+}
+
+object AnswerSerializer : KSerializer<Answer> {
+    override val descriptor = JsonPrimitive.serializer().descriptor
+
+    override fun serialize(encoder: Encoder, value: Answer) =
+        encoder.encodeSerializableValue(JsonPrimitive.serializer(), value.value)
+
+    override fun deserialize(decoder: Decoder): Answer {
+        val value = decoder.decodeSerializableValue(JsonPrimitive.serializer())
+        return Answer.fromValue(value)
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_infers_enum_value_types() {
+    let source_text = r#"
+    enum Limit {
+      Small = 1,
+      Huge = 2 ** 40,
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+@Serializable(with = LimitSerializer::class)
+enum class Limit(val value: Long) {
+    Small(1L),
+    Huge(1099511627776L);
+
+    companion object {
+        fun fromValue(value: Long): Limit? = entries.firstOrNull { it.value == value }
+    }
+}
+
+object LimitSerializer : KSerializer<Limit> {
+    override val descriptor = PrimitiveSerialDescriptor("Limit", PrimitiveKind.LONG)
+
+    override fun serialize(encoder: Encoder, value: Limit) =
+        encoder.encodeLong(value.value)
+
+    override fun deserialize(decoder: Decoder): Limit {
+        val value = decoder.decodeLong()
+        return Limit.fromValue(value)
+            ?: throw SerializationException("Unknown Limit value $value")
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_escapes_reserved_words_and_invalid_names() {
+    let source_text = r#"
+    interface Query {
+      readonly default: string;
+      readonly in: number;
+      readonly "content-type": string;
+      readonly "1st": boolean;
+      find(object: string, is: boolean): void;
+    }
+
+    interface Options {
+      readonly class: string;
+      readonly "max-age"?: number;
+    }
+
+    enum Header {
+      "content-type" = "Content-Type",
+      default = "default",
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+interface Query {
+    val default: String
+    val `in`: Double
+    @SerialName("content-type") val contentType: String
+    @SerialName("1st") val _1st: Boolean
+    fun find(`object`: String, `is`: Boolean): Unit
+}
+
+@Serializable
+data class Options (
+    val `class`: String,
+    @SerialName("max-age") val maxAge: Double
+)
+
+@Serializable
+enum class Header(val value: String) {
+    @SerialName("Content-Type") contentType("Content-Type"),
+    default("default");
+
+    companion object {
+        fun fromValue(value: String): Header? = entries.firstOrNull { it.value == value }
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_keeps_wire_keys_of_renamed_members() {
+    let source_text = r#"
+    interface Session {
+      readonly access_token: string;
+      readonly "expires-in": number;
+      readonly scope: string;
+    }
+
+    enum Grant {
+      authorization_code = "authorization_code",
+      refresh_token = "refresh_token",
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.kotlin.naming.properties = Some(NamingConvention::CamelCase);
+    config.kotlin.naming.enum_cases = Some(NamingConvention::CamelCase);
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+@Serializable
+data class Session (
+    @SerialName("access_token") val accessToken: String,
+    @SerialName("expires-in") val expiresIn: Double,
+    val scope: String
+)
+
+@Serializable
+enum class Grant(val value: String) {
+    @SerialName("authorization_code") authorizationCode("authorization_code"),
+    @SerialName("refresh_token") refreshToken("refresh_token");
+
+    companion object {
+        fun fromValue(value: String): Grant? = entries.firstOrNull { it.value == value }
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_naming_conventions() {
+    let source_text = r#"
+    interface user_profile {
+      readonly display_name: string;
+      readonly "home-page"?: string;
+      readonly status: account_status;
+      fetch_friends(): user_profile[];
+    }
+
+    enum account_status {
+      ACTIVE = "active",
+      SUSPENDED = "suspended",
+    }
+    "#;
+
+    let mut config = TransformConfig::default();
+    config.kotlin.naming = NamingConventions {
+      types: Some(NamingConvention::PascalCase),
+      properties: Some(NamingConvention::CamelCase),
+      methods: Some(NamingConvention::CamelCase),
+      enum_cases: Some(NamingConvention::PascalCase),
+    };
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+interface UserProfile {
+    @SerialName("display_name") val displayName: String
+    @SerialName("home-page") val homePage: String
+    val status: AccountStatus
+    fun fetchFriends(): List<UserProfile>
+}
+
+@Serializable
+enum class AccountStatus(val value: String) {
+    @SerialName("active") Active("active"),
+    @SerialName("suspended") Suspended("suspended");
+
+    companion object {
+        fun fromValue(value: String): AccountStatus? = entries.firstOrNull { it.value == value }
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_internal_visibility() {
+    let source_text = r#"
+    export interface User {
+      readonly id: string;
+      readonly role: Role;
+    }
+
+    export enum Role {
+      Admin = "admin",
+      Guest = "guest",
+    }
+    "#;
+
+    let mut config = TransformConfig::default();
+    config.kotlin.visibility = KotlinVisibility::Internal;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+@Serializable
+internal data class User (
+    val id: String,
+    val role: Role
+)
+
+@Serializable
+internal enum class Role(val value: String) {
+    @SerialName("admin") Admin("admin"),
+    @SerialName("guest") Guest("guest");
+
+    companion object {
+        fun fromValue(value: String): Role? = entries.firstOrNull { it.value == value }
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_async_styles() {
+    let source_text = r#"
+    interface UserService {
+      readonly currentUser: Promise<User>;
+      getUser(id: string): Promise<User>;
+      logout(): Promise<void>;
+      onRefresh: (force: boolean) => Promise<boolean>;
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+    let expected_code = r#"import kotlinx.coroutines.Deferred
+
+interface UserService {
+    val currentUser: Deferred<User>
+    suspend fun getUser(id: String): User
+    suspend fun logout(): Unit
+    var onRefresh: suspend (force: Boolean) -> Boolean
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+
+    let mut config = TransformConfig::default();
+    config.kotlin.async_style = KotlinAsyncStyle::Callback;
+    let transformed_code = transform(source_text, &config);
+    let expected_code = r#"
+interface UserService {
+    val currentUser: ((Result<User>) -> Unit) -> Unit
+    fun getUser(id: String, callback: (Result<User>) -> Unit): Unit
+    fun logout(callback: (Result<Unit>) -> Unit): Unit
+    var onRefresh: (force: Boolean, callback: (Result<Boolean>) -> Unit) -> Unit
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+
+    config.kotlin.async_style = KotlinAsyncStyle::CompletableFuture;
+    let transformed_code = transform(source_text, &config);
+    let expected_code = r#"import java.util.concurrent.CompletableFuture
+
+interface UserService {
+    val currentUser: CompletableFuture<User>
+    fun getUser(id: String): CompletableFuture<User>
+    fun logout(): CompletableFuture<Unit>
+    var onRefresh: (force: Boolean) -> CompletableFuture<Boolean>
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_streams() {
+    let source_text = r#"
+    interface ChatClient {
+      readonly messages: AsyncIterable<Message>;
+      readonly presence: Observable<Presence>;
+      onTyping(listener: (e: TypingEvent) => void): () => void;
+      onDisconnect(listener: () => void): void;
+      send(text: string): void;
+    }
+    "#;
+
+    let mut config = TransformConfig::default();
+    config.kotlin.listener_streams = true;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import kotlinx.coroutines.flow.Flow
+
+interface ChatClient {
+    val messages: Flow<Message>
+    val presence: Flow<Presence>
+    val typingFlow: Flow<TypingEvent>
+    val disconnectFlow: Flow<Unit>
+    fun send(text: String): Unit
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_codes_enum_entries_by_their_value() {
+    let source_text = r#"
+    enum Currency {
+      US_DOLLAR = "usd",
+      EURO = "EURO",
+    }
+
+    enum Direction {
+      Up,
+      Down,
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.kotlin.naming.enum_cases = Some(NamingConvention::CamelCase);
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+@Serializable
+enum class Currency(val value: String) {
+    @SerialName("usd") usDollar("usd"),
+    @SerialName("EURO") euro("EURO");
+
+    companion object {
+        fun fromValue(value: String): Currency? = entries.firstOrNull { it.value == value }
+    }
+}
+@Serializable(with = DirectionSerializer::class)
+enum class Direction(val value: Int) {
+    up(0),
+    down(1);
+
+    companion object {
+        fun fromValue(value: Int): Direction? = entries.firstOrNull { it.value == value }
+    }
+}
+
+object DirectionSerializer : KSerializer<Direction> {
+    override val descriptor = PrimitiveSerialDescriptor("Direction", PrimitiveKind.INT)
+
+    override fun serialize(encoder: Encoder, value: Direction) =
+        encoder.encodeInt(value.value)
+
+    override fun deserialize(decoder: Decoder): Direction {
+        val value = decoder.decodeInt()
+        return Direction.fromValue(value)
+            ?: throw SerializationException("Unknown Direction value $value")
+    }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+    assert!(transformed_code.contains("@SerialName(\"usd\") usDollar(\"usd\")"));
+  }
+}
@@ -1,22 +1,35 @@
 use oxc_ast::ast::Program;
 
-use super::kotlin_type_trait::KotlinType;
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::{kotlin_imports::KOTLIN_IMPORTS, kotlin_type_trait::KotlinType};
 
 pub struct KotlinTransformer;
 
 impl KotlinTransformer {
-  pub fn transform(ast_program: &Program) -> String {
+  pub fn transform(ast_program: &Program, ctx: &TransformContext) -> String {
     let mut import_banners = String::new();
     let mut output = String::new();
 
     for statement in &ast_program.body {
-      let statement_code = statement.to_kotlin_type();
+      let statement_code = statement.to_kotlin_type(ctx);
       output.push_str(&statement_code);
     }
 
-    // TODO: for now a naive accroach, think about better solution
-    if output.contains("suspend ") || output.contains("Deferred") {
-      import_banners.push_str("import kotlinx.coroutines.Deferred\n");
+    for (_, generated_code) in ctx.take_generated_declarations() {
+      output.push_str(&generated_code);
+    }
+
+    // types named like a declaration are referred to by their qualified name
+    let imported_types = ctx.take_imported_types();
+    for (type_name, import_path) in KOTLIN_IMPORTS {
+      if imported_types.contains(type_name) && !ctx.is_declared(type_name) {
+        import_banners.push_str(&format!("import {import_path}\n"));
+      }
+    }
+
+    for import_path in ctx.take_mapping_imports() {
+      import_banners.push_str(&format!("import {import_path}\n"));
     }
 
     format!("{import_banners}\n{output}")
@@ -1,27 +1,60 @@
 use oxc_ast::ast::{
   BindingPatternKind, Declaration, ExportNamedDeclaration, FormalParameters, PropertyKey,
-  Statement, TSEnumDeclaration, TSEnumMember, TSEnumMemberName, TSFunctionType,
-  TSInterfaceDeclaration, TSSignature, TSType, TSTypeReference,
+  Statement, TSEnumDeclaration, TSEnumMember, TSEnumMemberName, TSFunctionType, TSIndexSignature,
+  TSInterfaceDeclaration, TSIntersectionType, TSPropertySignature, TSSignature, TSTupleType,
+  TSType, TSTypeAliasDeclaration, TSTypeReference,
 };
 
-use crate::languages::{
-  kotlin::{kotlin_enum_display_type_trait::KotlinEnumDisplayType, kotlin_style},
-  shared::{
-    enum_trait::{GetEnumDisplayValue, IsEnumWithInitializerType},
-    is_async_trait::IsAsyncType,
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+  config::transform_config::{KotlinBigIntType, UnknownType},
+  languages::{
+    kotlin::{
+      kotlin_additional_properties::{
+        get_kotlin_additional_properties_annotations, get_kotlin_additional_properties_serializer,
+      },
+      kotlin_async_signature::{
+        KotlinAsyncSignature, get_kotlin_async_signature, to_kotlin_async_type,
+      },
+      kotlin_doc_comment_trait::get_kotlin_doc_comment,
+      kotlin_enum_display_type_trait::{KotlinEnumDisplayType, to_kotlin_enum_literal},
+      kotlin_enum_serializer::get_kotlin_enum_serializer,
+      kotlin_identifier::{to_kotlin_identifier, to_kotlin_member_name},
+      kotlin_imports::import_kotlin_type,
+      kotlin_style,
+      kotlin_visibility::get_kotlin_visibility,
+    },
+    shared::{
+      branded_type_trait::BrandedType,
+      enum_values::{EnumValue, get_enum_values_or_report, is_heterogeneous},
+      flags_enum::get_flag_values,
+      identifier::unescape_identifier,
+      index_signature::{get_index_key_type, get_index_signature, is_dictionary_type},
+      intersection_members::{get_intersected_interfaces, get_merged_members},
+      is_async_trait::IsAsyncType,
+      member_name_trait::MemberName,
+      number_kind::NumberKind,
+      stream_types::{STREAM_TYPE_NAMES, get_listener_subscription},
+      transform_context::TransformContext,
+      tuple_elements::get_tuple_elements,
+      type_mapping_trait::NativeTypeMapping,
+    },
   },
+  utils::naming_utils::to_pascal_case,
 };
 
 use super::kotlin_is_interface_type_trait::KotlinIsInterfaceType;
 
 pub trait KotlinType {
-  fn to_kotlin_type(&self) -> String;
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String;
 }
 
 impl KotlinType for PropertyKey<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, _ctx: &TransformContext) -> String {
     match self {
-      PropertyKey::StaticIdentifier(id_name) => id_name.name.to_string(),
+      PropertyKey::StaticIdentifier(id_name) => to_kotlin_identifier(&id_name.name),
+      PropertyKey::StringLiteral(literal) => to_kotlin_identifier(&literal.value),
       PropertyKey::Identifier(id_name) => id_name.to_string(),
       _ => "unknown-PropertyKey".to_owned(),
     }
@@ -29,23 +62,38 @@ impl KotlinType for PropertyKey<'_> {
 }
 
 impl KotlinType for BindingPatternKind<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, _ctx: &TransformContext) -> String {
     match self {
-      BindingPatternKind::BindingIdentifier(val) => val.name.to_string(),
+      BindingPatternKind::BindingIdentifier(val) => to_kotlin_identifier(&val.name),
       _ => "unknown-BindingPatternKind".to_owned(),
     }
   }
 }
 
 impl KotlinType for TSTypeReference<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
     let type_name = self.type_name.to_string();
+    if let Some(type_mapping) = ctx.config.kotlin.type_mappings.get(&type_name) {
+      let type_args = self
+        .type_parameters
+        .as_ref()
+        .map(|x| {
+          x.params
+            .iter()
+            .map(|x| x.to_kotlin_type(ctx))
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      ctx.add_mapping_imports(&type_mapping.imports);
+      return type_mapping.to_native_type(&type_args, "<", ">");
+    }
+
     match type_name.as_str() {
       "Promise" => self
         .type_parameters
         .as_ref()
         .and_then(|x| x.params.first())
-        .map(|x| x.to_kotlin_type())
+        .map(|x| x.to_kotlin_type(ctx))
         .unwrap_or_else(|| "Any".into()),
 
       "Array" | "ReadonlyArray" => format!(
@@ -54,7 +102,7 @@ impl KotlinType for TSTypeReference<'_> {
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.first())
-          .map(|x| x.to_kotlin_type())
+          .map(|x| x.to_kotlin_type(ctx))
           .unwrap_or_else(|| "Any".into())
       ),
 
@@ -63,14 +111,14 @@ impl KotlinType for TSTypeReference<'_> {
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.first())
-          .map(|x| x.to_kotlin_type())
+          .map(|x| x.to_kotlin_type(ctx))
           .unwrap_or_else(|| "Any".into());
 
         let val_str = self
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.get(1))
-          .map(|x| x.to_kotlin_type())
+          .map(|x| x.to_kotlin_type(ctx))
           .unwrap_or_else(|| "Any".into());
 
         format!("Map<{key_str}, {val_str}>")
@@ -81,64 +129,191 @@ impl KotlinType for TSTypeReference<'_> {
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.first())
-          .map(|x| x.to_kotlin_type())
+          .map(|x| x.to_kotlin_type(ctx))
           .unwrap_or_else(|| "Any".into());
         format!("{type_name}<{val_str}>")
       }
 
-      _ => type_name,
+      "Date" => import_kotlin_type(ctx, "Instant"),
+      "Uint8Array" | "ArrayBuffer" => "ByteArray".to_string(),
+      name if STREAM_TYPE_NAMES.contains(&name) => format!(
+        "{}<{}>",
+        import_kotlin_type(ctx, "Flow"),
+        self
+          .type_parameters
+          .as_ref()
+          .and_then(|x| x.params.first())
+          .map(|x| x.to_kotlin_type(ctx))
+          .unwrap_or_else(|| "Any".into())
+      ),
+      "URL" => import_kotlin_type(ctx, "URI"),
+      "Error" => "Throwable".to_string(),
+      "RegExp" => "Regex".to_string(),
+
+      _ => match ctx.integer_alias(&type_name) {
+        Some(NumberKind::Long) => "Long".to_string(),
+        Some(_) => "Int".to_string(),
+        None => ctx.native_type_name(&type_name, "kotlin"),
+      },
     }
   }
 }
 
 impl KotlinType for TSFunctionType<'_> {
   /// this is invoked from second level functions
-  fn to_kotlin_type(&self) -> String {
-    let type_name = self.return_type.type_annotation.to_kotlin_type();
-    let fn_params = self.params.to_kotlin_type();
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.return_type.type_annotation.to_kotlin_type(ctx);
+    let fn_params = self.params.to_kotlin_type(ctx);
 
     format!("({fn_params}) -> {type_name}")
   }
 }
 
 impl KotlinType for TSType<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
     match self {
       TSType::TSStringKeyword(_) => "String".to_string(),
-      TSType::TSNumberKeyword(_) => "Double".to_string(),
+      TSType::TSNumberKeyword(_) => match ctx.number_kind() {
+        NumberKind::Double => "Double".to_string(),
+        NumberKind::Int => "Int".to_string(),
+        NumberKind::Long => "Long".to_string(),
+      },
       TSType::TSBooleanKeyword(_) => "Boolean".to_string(),
       TSType::TSVoidKeyword(_) => "Unit".to_string(),
       TSType::TSObjectKeyword(_) => "Map<String, Any>".to_string(),
-      TSType::TSTypeReference(val) => val.to_kotlin_type(),
-      TSType::TSFunctionType(fn_type) => fn_type.to_kotlin_type(),
+      TSType::TSBigIntKeyword(_) => match ctx.config.kotlin.bigint_type {
+        KotlinBigIntType::Long => "Long".to_string(),
+        KotlinBigIntType::BigInteger => import_kotlin_type(ctx, "BigInteger"),
+      },
+      TSType::TSUnknownKeyword(_) | TSType::TSAnyKeyword(_) => {
+        match ctx.config.kotlin.unknown_type {
+          UnknownType::Json => import_kotlin_type(ctx, "JsonElement"),
+          UnknownType::Any => "Any".to_string(),
+        }
+      }
+      TSType::TSTypeReference(val) => val.to_kotlin_type(ctx),
+      TSType::TSTupleType(tuple_type) => tuple_type.to_kotlin_type(ctx),
+      TSType::TSFunctionType(fn_type) => fn_type.to_kotlin_type(ctx),
       TSType::TSArrayType(array_type) => {
-        let el_type = array_type.element_type.to_kotlin_type();
+        let el_type = array_type.element_type.to_kotlin_type(ctx);
         format!("List<{el_type}>")
       }
-      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_kotlin_type(),
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_kotlin_type(ctx),
+      TSType::TSTypeLiteral(type_literal) => {
+        let members = type_literal.members.iter().collect::<Vec<_>>();
+        match get_index_signature(&members) {
+          Some(index_sig) if is_dictionary_type(&members) => index_sig.to_kotlin_type(ctx),
+          _ => "Any".to_string(),
+        }
+      }
       _ => "Any".to_string(),
     }
   }
 }
 
+/// `[key: string]: T` as a map, numeric keys are integers
+impl KotlinType for TSIndexSignature<'_> {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    let key_type = ctx.with_number_kind(NumberKind::Int, || {
+      get_index_key_type(self)
+        .map(|key_type| key_type.to_kotlin_type(ctx))
+        .unwrap_or_else(|| "String".to_string())
+    });
+    format!("Map<{key_type}, {}>", to_kotlin_index_value_type(ctx, self))
+  }
+}
+
+fn to_kotlin_index_value_type(ctx: &TransformContext, index_sig: &TSIndexSignature) -> String {
+  let number_kind = ctx.member_number_kind(index_sig.span, "");
+  ctx.with_number_kind(number_kind, || {
+    index_sig
+      .type_annotation
+      .type_annotation
+      .to_kotlin_type(ctx)
+  })
+}
+
+impl KotlinType for TSTupleType<'_> {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    let elements = get_tuple_elements(self);
+
+    // `[...string[]]` is a plain list
+    if let [element] = elements.as_slice()
+      && element.rest
+    {
+      return element.element_type.to_kotlin_type(ctx);
+    }
+
+    if elements.iter().any(|x| x.rest) {
+      ctx.report(format!(
+        "Kotlin tuples have no rest elements, `{}` is emitted as `List<Any>`",
+        ctx.source_text(self.span)
+      ));
+      return "List<Any>".to_string();
+    }
+
+    let element_types = elements
+      .iter()
+      .map(|x| {
+        let optional = if x.optional { "?" } else { "" };
+        format!("{}{optional}", x.element_type.to_kotlin_type(ctx))
+      })
+      .collect::<Vec<_>>();
+
+    let is_labelled = elements.iter().any(|x| x.label.is_some());
+    match element_types.as_slice() {
+      [first, second] if !is_labelled => format!("Pair<{first}, {second}>"),
+      [first, second, third] if !is_labelled => format!("Triple<{first}, {second}, {third}>"),
+      _ => {
+        let fields = elements
+          .iter()
+          .zip(&element_types)
+          .enumerate()
+          .map(|(index, (element, element_type))| {
+            let field_name = element
+              .label
+              .clone()
+              .unwrap_or_else(|| format!("item{}", index + 1));
+            let default_value = if element.optional { " = null" } else { "" };
+            format!(
+              "{}val {field_name}: {element_type}{default_value}",
+              kotlin_style::INDENT_SPACE
+            )
+          })
+          .collect::<Vec<_>>()
+          .join(",\n");
+
+        let class_name = ctx
+          .name_hint()
+          .unwrap_or_else(|| format!("Tuple{}", elements.len()));
+        let visibility = get_kotlin_visibility(ctx);
+        let annotation = get_kotlin_serializable_annotation(ctx);
+        ctx.add_generated_declaration(&class_name, |class_name| {
+          format!("{annotation}{visibility}data class {class_name} (\n{fields}\n)\n\n")
+        })
+      }
+    }
+  }
+}
+
 impl KotlinType for FormalParameters<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
     self
       .items
       .iter()
       .map(|param| {
-        let type_annotation = param
-          .pattern
-          .type_annotation
-          .as_ref()
-          .map(|t| t.type_annotation.to_kotlin_type())
-          .unwrap_or_else(|| "Any".to_string());
-
-        format!(
-          "{}: {}",
-          param.pattern.kind.to_kotlin_type(),
-          type_annotation
-        )
+        let param_name = param.pattern.kind.to_kotlin_type(ctx);
+        let number_kind = ctx.member_number_kind(param.span, &param_name);
+        let type_annotation = ctx.with_number_kind(number_kind, || {
+          param
+            .pattern
+            .type_annotation
+            .as_ref()
+            .map(|t| t.type_annotation.to_kotlin_type(ctx))
+            .unwrap_or_else(|| "Any".to_string())
+        });
+
+        format!("{param_name}: {type_annotation}")
       })
       .collect::<Vec<_>>()
       .join(", ")
@@ -146,78 +321,137 @@ impl KotlinType for FormalParameters<'_> {
 }
 
 impl KotlinType for TSSignature<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    let number_kind = match self {
+      TSSignature::TSMethodSignature(method_sig) => ctx.method_number_kind(method_sig.span),
+      _ => ctx.member_number_kind(self.span(), &self.to_member_name()),
+    };
+    ctx.with_number_kind(number_kind, || self.to_kotlin_member_type(ctx))
+  }
+}
+
+trait KotlinMemberType {
+  fn to_kotlin_member_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl KotlinMemberType for TSSignature<'_> {
+  fn to_kotlin_member_type(&self, ctx: &TransformContext) -> String {
     match self {
       TSSignature::TSPropertySignature(prop_sig) => {
-        let prop_name = prop_sig.key.to_kotlin_type();
+        let overrides = ctx.native_overrides(prop_sig.span, "kotlin");
+        let prop_name = get_kotlin_property_name(ctx, prop_sig);
         let readonly = if prop_sig.readonly { "val" } else { "var" };
 
         // If property is a arrow function
-        if let Some(annotation) = prop_sig.type_annotation.as_ref() {
-          if let TSType::TSFunctionType(fn_type) = &annotation.type_annotation {
-            let fn_return_type = fn_type.return_type.type_annotation.to_kotlin_type();
-            let fn_params = fn_type.params.to_kotlin_type();
-            let async_val = if fn_type.return_type.type_annotation.is_async_type() {
-              "suspend "
-            } else {
-              ""
-            };
-
-            return format!(
-              "{}{} {}: {}({}) -> {}",
-              kotlin_style::INDENT_SPACE,
-              readonly,
-              prop_name,
-              async_val,
-              fn_params,
-              fn_return_type
-            );
-          }
+        if overrides.native_type.is_none()
+          && let Some(annotation) = prop_sig.type_annotation.as_ref()
+          && let TSType::TSFunctionType(fn_type) = &annotation.type_annotation
+        {
+          let fn_return_type = fn_type.return_type.type_annotation.to_kotlin_type(ctx);
+          let fn_params = fn_type.params.to_kotlin_type(ctx);
+          let signature = if fn_type.return_type.type_annotation.is_async_type() {
+            get_kotlin_async_signature(ctx, fn_params, &fn_return_type)
+          } else {
+            KotlinAsyncSignature {
+              modifier: "",
+              params: fn_params,
+              return_type: fn_return_type,
+            }
+          };
+
+          return format!(
+            "{}{} {}: {}({}) -> {}",
+            kotlin_style::INDENT_SPACE,
+            readonly,
+            prop_name,
+            signature.modifier,
+            signature.params,
+            signature.return_type
+          );
         }
 
-        let type_annotation = prop_sig
-          .type_annotation
-          .as_ref()
-          .map(|annotation| annotation.type_annotation.to_kotlin_type())
-          .unwrap_or_default();
+        let type_annotation = overrides.native_type.unwrap_or_else(|| {
+          prop_sig
+            .type_annotation
+            .as_ref()
+            .map(|annotation| annotation.type_annotation.to_kotlin_type(ctx))
+            .unwrap_or_default()
+        });
 
         let prop_return_type = if prop_sig.is_async_type() {
-          // TODO: `import kotlinx.coroutines.Deferred` should included in banner
-          format!("Deferred<{type_annotation}>")
+          to_kotlin_async_type(ctx, &type_annotation)
         } else {
           type_annotation.to_string()
         };
 
+        // renamed and sanitized properties keep their TS key
+        let serial_name = if is_kotlin_renamed_property(ctx, self) {
+          format!(
+            "@{}(\"{}\") ",
+            import_kotlin_type(ctx, "SerialName"),
+            self.to_member_name()
+          )
+        } else {
+          "".to_string()
+        };
+
         format!(
-          "{}{} {}: {}",
+          "{}{}{} {}: {}",
           kotlin_style::INDENT_SPACE,
+          serial_name,
           readonly,
           prop_name,
           prop_return_type
         )
       }
       TSSignature::TSMethodSignature(method_sig) => {
-        let params = method_sig.params.to_kotlin_type();
-
-        let return_type = method_sig
-          .return_type
-          .as_ref()
-          .map(|r| r.type_annotation.to_kotlin_type())
-          .unwrap_or_else(|| "".to_string());
+        let overrides = ctx.native_overrides(method_sig.span, "kotlin");
+        if ctx.config.kotlin.listener_streams
+          && let Some(subscription) = get_listener_subscription(method_sig)
+        {
+          let event_type = subscription
+            .event_type
+            .map(|event_type| event_type.to_kotlin_type(ctx))
+            .unwrap_or_else(|| "Unit".to_string());
+          let prop_name = overrides
+            .name
+            .unwrap_or_else(|| to_kotlin_identifier(&format!("{}Flow", subscription.event_name)));
+          return format!(
+            "{}val {prop_name}: {}<{event_type}>",
+            kotlin_style::INDENT_SPACE,
+            import_kotlin_type(ctx, "Flow")
+          );
+        }
 
-        let func_name = method_sig.key.to_kotlin_type();
-        let async_val = if method_sig.is_async_type() {
-          "suspend "
+        let params = method_sig.params.to_kotlin_type(ctx);
+
+        let return_type = overrides.native_type.unwrap_or_else(|| {
+          method_sig
+            .return_type
+            .as_ref()
+            .map(|r| r.type_annotation.to_kotlin_type(ctx))
+            .unwrap_or_else(|| "".to_string())
+        });
+
+        let func_name = overrides.name.unwrap_or_else(|| {
+          to_kotlin_member_name(ctx, &method_sig.key, ctx.config.kotlin.naming.methods)
+        });
+        let signature = if method_sig.is_async_type() {
+          get_kotlin_async_signature(ctx, params, &return_type)
         } else {
-          ""
+          KotlinAsyncSignature {
+            modifier: "",
+            params,
+            return_type,
+          }
         };
         format!(
           "{}{}fun {}({}): {}",
           kotlin_style::INDENT_SPACE,
-          async_val,
+          signature.modifier,
           func_name,
-          params,
-          return_type
+          signature.params,
+          signature.return_type
         )
       }
       _ => "// unknown-signature".to_string(),
@@ -225,105 +459,439 @@ impl KotlinType for TSSignature<'_> {
   }
 }
 
+fn get_kotlin_property_name(ctx: &TransformContext, prop_sig: &TSPropertySignature) -> String {
+  ctx
+    .native_overrides(prop_sig.span, "kotlin")
+    .name
+    .unwrap_or_else(|| {
+      to_kotlin_member_name(ctx, &prop_sig.key, ctx.config.kotlin.naming.properties)
+    })
+}
+
+/// Whether a property is declared under another name than its TS key and needs `@SerialName`
+fn is_kotlin_renamed_property(ctx: &TransformContext, signature: &TSSignature) -> bool {
+  match signature {
+    TSSignature::TSPropertySignature(prop_sig) => {
+      unescape_identifier(&get_kotlin_property_name(ctx, prop_sig)) != signature.to_member_name()
+    }
+    _ => false,
+  }
+}
+
 impl KotlinType for TSInterfaceDeclaration<'_> {
-  fn to_kotlin_type(&self) -> String {
-    let interface_name = self.id.name.to_string();
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    let members = self.body.body.iter().collect::<Vec<_>>();
+    to_kotlin_record_type(ctx, self.span, &self.id.name, &members)
+  }
+}
 
-    let is_interface = self.is_kotlin_interface_type();
+/// Interface or data class holding `members`, shared by interfaces and merged intersections
+fn to_kotlin_record_type(
+  ctx: &TransformContext,
+  span: Span,
+  type_name: &str,
+  members: &[&TSSignature],
+) -> String {
+  let overrides = ctx.native_overrides(span, "kotlin");
+  if overrides.is_ignored || overrides.native_type.is_some() {
+    return "".to_string();
+  }
 
-    if is_interface {
-      let body_data = self
-        .body
-        .body
-        .iter()
-        .map(|signature| signature.to_kotlin_type())
-        .collect::<Vec<_>>()
-        .join("\n");
+  let interface_name = overrides
+    .name
+    .unwrap_or_else(|| ctx.declared_type_name(type_name, "kotlin"));
+  let doc_comment = get_kotlin_doc_comment(ctx, span, "");
+  let visibility = get_kotlin_visibility(ctx);
+
+  let index_sig = get_index_signature(members);
+  if let Some(index_sig) = index_sig
+    && is_dictionary_type(members)
+  {
+    return format!(
+      "{doc_comment}{visibility}typealias {interface_name} = {}\n\n",
+      index_sig.to_kotlin_type(ctx)
+    );
+  }
 
-      format!("interface {interface_name} {{\n{body_data}\n}}\n\n")
-    } else {
-      let body_data = self
-        .body
-        .body
-        .iter()
-        .map(|signature| signature.to_kotlin_type())
-        .collect::<Vec<_>>()
-        .join(",\n");
-      format!("data class {interface_name} (\n{body_data}\n)\n\n")
+  let is_interface = members.iter().any(|x| x.is_kotlin_interface_type());
+  let members = members
+    .iter()
+    .filter(|signature| !ctx.native_overrides(signature.span(), "kotlin").is_ignored)
+    .filter(|signature| is_interface || !matches!(signature, TSSignature::TSIndexSignature(_)))
+    .collect::<Vec<_>>();
+
+  let mut body_data = members
+    .iter()
+    .map(|signature| {
+      // inline types that need a declaration are named after the member, `OrderRange`
+      let name_hint = format!(
+        "{interface_name}{}",
+        to_pascal_case(&signature.to_member_name())
+      );
+      format!(
+        "{}{}",
+        get_kotlin_doc_comment(ctx, signature.span(), kotlin_style::INDENT_SPACE),
+        ctx.with_name_hint(name_hint, || signature.to_kotlin_type(ctx))
+      )
+    })
+    .collect::<Vec<_>>();
+
+  if is_interface {
+    format!(
+      "{doc_comment}{visibility}interface {interface_name} {{\n{}\n}}\n\n",
+      body_data.join("\n")
+    )
+  } else if let Some(index_sig) = index_sig {
+    // the keys the class does not declare are collected by a custom serializer
+    let property_names = members
+      .iter()
+      .filter(|signature| matches!(signature, TSSignature::TSPropertySignature(_)))
+      .map(|signature| signature.to_member_name())
+      .collect::<Vec<_>>();
+    body_data.push(format!(
+      "{}val additionalProperties: Map<String, {}> = emptyMap()",
+      kotlin_style::INDENT_SPACE,
+      to_kotlin_index_value_type(ctx, index_sig)
+    ));
+
+    format!(
+      "{doc_comment}{}{visibility}data class {interface_name} (\n{}\n)\n\n{}",
+      get_kotlin_additional_properties_annotations(ctx, &interface_name),
+      body_data.join(",\n"),
+      get_kotlin_additional_properties_serializer(
+        ctx,
+        &interface_name,
+        &property_names,
+        visibility
+      )
+    )
+  } else {
+    format!(
+      "{doc_comment}{}{visibility}data class {interface_name} (\n{}\n)\n\n",
+      get_kotlin_serializable_annotation(ctx),
+      body_data.join(",\n")
+    )
+  }
+}
+
+/// Interface extending every part when they are all interfaces, otherwise a
+/// single record with the merged members
+fn to_kotlin_intersection_type(
+  ctx: &TransformContext,
+  alias_decl: &TSTypeAliasDeclaration,
+  intersection_type: &TSIntersectionType,
+) -> String {
+  let alias_name = alias_decl.id.name.to_string();
+
+  if let Some(interfaces) = get_intersected_interfaces(intersection_type, ctx)
+    && interfaces.iter().all(|x| x.is_kotlin_interface_type())
+  {
+    let interface_names = interfaces
+      .iter()
+      .map(|x| ctx.native_type_name(&x.id.name, "kotlin"))
+      .collect::<Vec<_>>()
+      .join(", ");
+    let doc_comment = get_kotlin_doc_comment(ctx, alias_decl.span, "");
+    let alias_name = ctx.native_type_name(&alias_name, "kotlin");
+
+    return format!(
+      "{doc_comment}{}interface {alias_name} : {interface_names}\n\n",
+      get_kotlin_visibility(ctx)
+    );
+  }
+
+  match get_merged_members(intersection_type, ctx, &alias_name) {
+    Some(members) => to_kotlin_record_type(ctx, alias_decl.span, &alias_name, &members),
+    None => {
+      ctx.report(format!(
+        "`{alias_name}` intersects types that are not object literals or interfaces of the same file"
+      ));
+      "// unknown-type-alias\n\n".to_string()
     }
   }
 }
 
 impl KotlinType for Declaration<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
     match self {
-      Declaration::TSInterfaceDeclaration(interface_decl) => interface_decl.to_kotlin_type(),
-      Declaration::TSEnumDeclaration(enum_decl) => enum_decl.to_kotlin_type(),
+      Declaration::TSInterfaceDeclaration(interface_decl) => interface_decl.to_kotlin_type(ctx),
+      Declaration::TSEnumDeclaration(enum_decl) => enum_decl.to_kotlin_type(ctx),
+      Declaration::TSTypeAliasDeclaration(alias_decl) => alias_decl.to_kotlin_type(ctx),
       _ => "// unknown-declaration".to_string(),
     }
   }
 }
 
 impl KotlinType for ExportNamedDeclaration<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
     self
       .declaration
       .as_ref()
-      .map(|d| d.to_kotlin_type())
+      .map(|d| d.to_kotlin_type(ctx))
       .unwrap_or_else(|| "// unknown-export-named-declaration".to_string())
   }
 }
 
 impl KotlinType for TSEnumMember<'_> {
-  fn to_kotlin_type(&self) -> String {
-    match &self.id {
-      TSEnumMemberName::Identifier(enum_id) => enum_id.to_string(),
-      TSEnumMemberName::String(enum_string) => enum_string.to_string(),
-    }
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    ctx
+      .native_overrides(self.span, "kotlin")
+      .name
+      .unwrap_or_else(|| {
+        let name = get_enum_member_name(self);
+        let name = match ctx.config.kotlin.naming.enum_cases {
+          Some(convention) => convention.apply(&name),
+          None => name,
+        };
+        to_kotlin_identifier(&name)
+      })
   }
 }
 
+fn get_enum_member_name(member: &TSEnumMember) -> String {
+  match &member.id {
+    TSEnumMemberName::Identifier(enum_id) => enum_id.name.to_string(),
+    TSEnumMemberName::String(enum_string) => enum_string.value.to_string(),
+  }
+}
+
+/// kotlinx serializes enum entries by name, `@SerialName` for an entry whose name is
+/// not `serial_name`, its string value or the TS name of an enum without values
+fn get_kotlin_enum_serial_name(
+  ctx: &TransformContext,
+  member: &TSEnumMember,
+  serial_name: &str,
+) -> String {
+  if unescape_identifier(&member.to_kotlin_type(ctx)) == serial_name {
+    "".to_string()
+  } else {
+    format!(
+      "@{}({}) ",
+      import_kotlin_type(ctx, "SerialName"),
+      to_kotlin_enum_literal(&EnumValue::String(serial_name.to_string()), "String")
+    )
+  }
+}
+
+/// Every data class, enum and value class gets a generated serializer, which
+/// `@SerialName` applies to
+fn get_kotlin_serializable_annotation(ctx: &TransformContext) -> String {
+  format!("@{}\n", import_kotlin_type(ctx, "Serializable"))
+}
+
 impl KotlinType for TSEnumDeclaration<'_> {
-  fn to_kotlin_type(&self) -> String {
-    let enum_name = self.id.to_string();
-    if self.is_enum_with_initializer_type() {
-      let enum_cases: String = self
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    let overrides = ctx.native_overrides(self.span, "kotlin");
+    if overrides.is_ignored || overrides.native_type.is_some() {
+      return "".to_string();
+    }
+
+    let enum_name = overrides
+      .name
+      .unwrap_or_else(|| ctx.declared_type_name(&self.id.name, "kotlin"));
+    let doc_comment = get_kotlin_doc_comment(ctx, self.span, "");
+    let visibility = get_kotlin_visibility(ctx);
+
+    if let Some(flag_values) = get_flag_values(ctx, self) {
+      let indent = kotlin_style::INDENT_SPACE;
+      let indent2 = indent.repeat(2);
+      let flag_members = self
         .members
         .iter()
-        .map(|x| {
+        .zip(flag_values)
+        .filter(|(x, _)| !ctx.native_overrides(x.span, "kotlin").is_ignored)
+        .map(|(x, flag_value)| {
           format!(
-            "{}{}({})",
-            kotlin_style::INDENT_SPACE,
-            x.to_kotlin_type(),
-            x.initializer
-              .as_ref()
-              .expect("Unable get initializer value from enum")
-              .get_enum_display_value()
+            "{}{indent2}val {} = {enum_name}({flag_value})",
+            get_kotlin_doc_comment(ctx, x.span, &indent2),
+            x.to_kotlin_type(ctx)
           )
         })
         .collect::<Vec<_>>()
         .join("\n");
-      let enum_type = self.to_kotlin_enum_display_type();
-      format!("enum class {enum_name}(val value: {enum_type}) {{ \n{enum_cases}\n}}\n")
+
+      return format!(
+        "{doc_comment}{}@JvmInline\n{visibility}value class {enum_name}(val value: Int) {{
+{indent}infix fun or(other: {enum_name}) = {enum_name}(value or other.value)
+
+{indent}operator fun contains(other: {enum_name}) = (value and other.value) == other.value
+
+{indent}companion object {{
+{flag_members}
+{indent}}}
+}}
+",
+        get_kotlin_serializable_annotation(ctx)
+      );
+    }
+
+    // implicit members are numbers as well, `A, B` are coded as 0 and 1
+    let has_unknown_case = ctx.config.kotlin.unknown_enum_case;
+    let enum_values = get_enum_values_or_report(ctx, self, &enum_name);
+
+    let indent = kotlin_style::INDENT_SPACE;
+    if let Some(enum_values) = enum_values {
+      // enums mixing strings and numbers keep their values as JSON
+      let is_mixed = is_heterogeneous(&enum_values);
+      let enum_type = if is_mixed {
+        "JsonPrimitive".to_string()
+      } else {
+        self.to_kotlin_enum_display_type()
+      };
+      // `enum_type` tells the kinds of values apart, `value_type` is how the code refers to it
+      let value_type = if is_mixed {
+        import_kotlin_type(ctx, "JsonPrimitive")
+      } else {
+        enum_type.clone()
+      };
+      // kotlinx codes entries by name, `@SerialName` carries a string value and
+      // other values need a serializer
+      let has_serializer = is_mixed || has_unknown_case || enum_type != "String";
+      let mut enum_cases = self
+        .members
+        .iter()
+        .zip(&enum_values)
+        .filter(|(x, _)| !ctx.native_overrides(x.span, "kotlin").is_ignored)
+        .map(|(x, enum_value)| {
+          let serial_name = match enum_value {
+            EnumValue::String(value) if !has_serializer => {
+              get_kotlin_enum_serial_name(ctx, x, value)
+            }
+            _ => "".to_string(),
+          };
+          let value_literal = if is_mixed {
+            format!("{value_type}({})", to_kotlin_enum_literal(enum_value, ""))
+          } else {
+            to_kotlin_enum_literal(enum_value, &enum_type)
+          };
+          format!(
+            "{}{indent}{serial_name}{}({value_literal})",
+            get_kotlin_doc_comment(ctx, x.span, indent),
+            x.to_kotlin_type(ctx)
+          )
+        })
+        .collect::<Vec<_>>();
+
+      let indent2 = indent.repeat(2);
+      let from_value = if has_unknown_case {
+        // a placeholder value, `UNKNOWN` never matches a decoded value
+        let unknown_value = match enum_type.as_str() {
+          "JsonPrimitive" => import_kotlin_type(ctx, "JsonNull"),
+          "String" => "\"\"".to_string(),
+          "Double" => "0.0".to_string(),
+          "Long" => "0L".to_string(),
+          _ => "0".to_string(),
+        };
+        enum_cases.push(format!("{indent}UNKNOWN({unknown_value})"));
+        format!(
+          "{indent2}fun fromValue(value: {value_type}): {enum_name} =\n{indent2}{indent}entries.firstOrNull {{ it != UNKNOWN && it.value == value }} ?: UNKNOWN"
+        )
+      } else {
+        format!(
+          "{indent2}fun fromValue(value: {value_type}): {enum_name}? = entries.firstOrNull {{ it.value == value }}"
+        )
+      };
+
+      let (annotation, serializer) = if has_serializer {
+        (
+          format!(
+            "@{}(with = {enum_name}Serializer::class)\n",
+            import_kotlin_type(ctx, "Serializable")
+          ),
+          format!(
+            "\n{}",
+            get_kotlin_enum_serializer(ctx, &enum_name, &enum_type, has_unknown_case, visibility)
+          ),
+        )
+      } else {
+        (get_kotlin_serializable_annotation(ctx), "".to_string())
+      };
+
+      format!(
+        "{doc_comment}{annotation}{visibility}enum class {enum_name}(val value: {value_type}) {{\n{};\n\n{indent}companion object {{\n{from_value}\n{indent}}}\n}}\n{serializer}",
+        enum_cases.join(",\n")
+      )
     } else {
       let enum_cases: String = self
         .members
         .iter()
-        .map(|x| format!("{}{}", kotlin_style::INDENT_SPACE, x.to_kotlin_type()))
+        .filter(|x| !ctx.native_overrides(x.span, "kotlin").is_ignored)
+        .map(|x| {
+          format!(
+            "{}{indent}{}{}",
+            get_kotlin_doc_comment(ctx, x.span, indent),
+            get_kotlin_enum_serial_name(ctx, x, &get_enum_member_name(x)),
+            x.to_kotlin_type(ctx)
+          )
+        })
         .collect::<Vec<_>>()
-        .join("\n");
+        .join(",\n");
+
+      format!(
+        "{doc_comment}{}{visibility}enum class {enum_name} {{\n{enum_cases}\n}}\n",
+        get_kotlin_serializable_annotation(ctx)
+      )
+    }
+  }
+}
+
+impl KotlinType for TSTypeAliasDeclaration<'_> {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
+    let overrides = ctx.native_overrides(self.span, "kotlin");
+    // integer aliases are emitted as the native `Int` / `Long`
+    if overrides.is_ignored
+      || overrides.native_type.is_some()
+      || ctx.integer_alias(&self.id.name).is_some()
+    {
+      return "".to_string();
+    }
+
+    if self.get_branded_base_type().is_none()
+      && let TSType::TSIntersectionType(intersection_type) = &self.type_annotation
+    {
+      return to_kotlin_intersection_type(ctx, self, intersection_type);
+    }
 
-      format!("enum class {enum_name} {{ \n{enum_cases}\n}}\n")
+    let alias_name = overrides
+      .name
+      .unwrap_or_else(|| ctx.declared_type_name(&self.id.name, "kotlin"));
+    if let TSType::TSTupleType(tuple_type) = &self.type_annotation {
+      let doc_comment = get_kotlin_doc_comment(ctx, self.span, "");
+      let tuple_type = ctx.with_name_hint(alias_name.clone(), || tuple_type.to_kotlin_type(ctx));
+
+      // a generated data class takes the name of the alias and its place
+      return match ctx.take_generated_declaration(&tuple_type) {
+        Some(code) if tuple_type == alias_name => format!("{doc_comment}{code}"),
+        _ => format!(
+          "{doc_comment}{}typealias {alias_name} = {tuple_type}\n\n",
+          get_kotlin_visibility(ctx)
+        ),
+      };
     }
+
+    let Some(base_type) = self.get_branded_base_type() else {
+      return "// unknown-type-alias\n\n".to_string();
+    };
+
+    let number_kind = ctx.member_number_kind(self.span, &self.id.name);
+    let value_type = ctx.with_number_kind(number_kind, || base_type.to_kotlin_type(ctx));
+    let doc_comment = get_kotlin_doc_comment(ctx, self.span, "");
+
+    format!(
+      "{doc_comment}{}@JvmInline\n{}value class {alias_name}(val value: {value_type})\n\n",
+      get_kotlin_serializable_annotation(ctx),
+      get_kotlin_visibility(ctx)
+    )
   }
 }
 
 impl KotlinType for Statement<'_> {
-  fn to_kotlin_type(&self) -> String {
+  fn to_kotlin_type(&self, ctx: &TransformContext) -> String {
     match self {
-      Statement::ExportNamedDeclaration(export_decl) => export_decl.to_kotlin_type(),
-      Statement::TSInterfaceDeclaration(interface_decl) => interface_decl.to_kotlin_type(),
-      Statement::TSEnumDeclaration(enum_decl) => enum_decl.to_kotlin_type(),
+      Statement::ExportNamedDeclaration(export_decl) => export_decl.to_kotlin_type(ctx),
+      Statement::TSInterfaceDeclaration(interface_decl) => interface_decl.to_kotlin_type(ctx),
+      Statement::TSEnumDeclaration(enum_decl) => enum_decl.to_kotlin_type(ctx),
+      Statement::TSTypeAliasDeclaration(alias_decl) => alias_decl.to_kotlin_type(ctx),
       _ => "// unknown-statement\n\n".to_string(),
     }
   }
@@ -0,0 +1,12 @@
+use crate::{
+  config::transform_config::KotlinVisibility,
+  languages::shared::transform_context::TransformContext,
+};
+
+/// Modifier of the generated top level declarations, public is the Kotlin default
+pub fn get_kotlin_visibility(ctx: &TransformContext) -> &'static str {
+  match ctx.config.kotlin.visibility {
+    KotlinVisibility::Public => "",
+    KotlinVisibility::Internal => "internal ",
+  }
+}
@@ -1,5 +1,16 @@
+pub mod kotlin_additional_properties;
+pub mod kotlin_async_signature;
+pub mod kotlin_doc_comment_trait;
 pub mod kotlin_enum_display_type_trait;
+pub mod kotlin_enum_serializer;
+pub mod kotlin_identifier;
+pub mod kotlin_imports;
 pub mod kotlin_is_interface_type_trait;
 pub mod kotlin_style;
 pub mod kotlin_transformer;
 pub mod kotlin_type_trait;
+pub mod kotlin_visibility;
+
+#[cfg(test)]
+#[path = "./kotlin_test.rs"]
+mod kotlin_test;
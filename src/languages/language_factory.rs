@@ -1,12 +1,26 @@
 use oxc_ast::ast::Program;
 
+use crate::config::transform_config::TransformConfig;
+
 use super::{
-  kotlin::kotlin_transformer::KotlinTransformer, swift::swift_transformer::SwiftTransformer,
+  go::go_transformer::GoTransformer,
+  java::java_transformer::JavaTransformer,
+  json_schema::json_schema_transformer::JsonSchemaTransformer,
+  kotlin::kotlin_transformer::KotlinTransformer,
+  proto::{proto_field_lock::ProtoFieldLock, proto_transformer::ProtoTransformer},
+  python::python_transformer::PythonTransformer,
+  shared::transform_context::TransformContext,
+  swift::swift_transformer::SwiftTransformer,
 };
 
 pub enum LanguageType {
   Swift,
   Kotlin,
+  Java,
+  Python,
+  Go,
+  JsonSchema,
+  Proto,
 }
 
 impl TryFrom<String> for LanguageType {
@@ -16,18 +30,65 @@ impl TryFrom<String> for LanguageType {
     match value.as_str() {
       "swift" => Ok(LanguageType::Swift),
       "kt" => Ok(LanguageType::Kotlin),
+      "java" => Ok(LanguageType::Java),
+      "py" => Ok(LanguageType::Python),
+      "go" => Ok(LanguageType::Go),
+      "schema.json" => Ok(LanguageType::JsonSchema),
+      "proto" => Ok(LanguageType::Proto),
       _ => Err("unknown language!"),
     }
   }
 }
 
+pub enum TransformOutput {
+  /// Code for the `--out` file
+  Single(String),
+  /// `(file name, code)` pairs, written next to the `--out` file
+  Multiple(Vec<(String, String)>),
+}
+
 pub struct LanguageFactory;
 
 impl LanguageFactory {
-  pub fn transform(target_language: &LanguageType, ast_program: &Program) -> String {
-    match target_language {
-      LanguageType::Swift => SwiftTransformer::transform(ast_program),
-      LanguageType::Kotlin => KotlinTransformer::transform(ast_program),
+  /// Fails when the proto lock file cannot be read or written
+  pub fn transform<'a>(
+    target_language: &LanguageType,
+    ast_program: &'a Program<'a>,
+    config: &TransformConfig,
+    out_file: &str,
+  ) -> Result<TransformOutput, String> {
+    let ctx = TransformContext::new(ast_program, config);
+    let transformed_output = match target_language {
+      LanguageType::Swift => {
+        TransformOutput::Single(SwiftTransformer::transform(ast_program, &ctx))
+      }
+      LanguageType::Kotlin => {
+        TransformOutput::Single(KotlinTransformer::transform(ast_program, &ctx))
+      }
+      LanguageType::Java => {
+        TransformOutput::Multiple(JavaTransformer::transform(ast_program, &ctx))
+      }
+      LanguageType::Python => {
+        TransformOutput::Single(PythonTransformer::transform(ast_program, &ctx))
+      }
+      LanguageType::Go => TransformOutput::Single(GoTransformer::transform(ast_program, &ctx)),
+      LanguageType::JsonSchema => {
+        TransformOutput::Single(JsonSchemaTransformer::transform(ast_program, &ctx))
+      }
+      LanguageType::Proto => {
+        // field numbers are persisted next to the output, e.g. `Contract.proto.lock`
+        let lock_file = format!("{out_file}.lock");
+        let mut field_lock = ProtoFieldLock::from_file(&lock_file)?;
+        let transformed_code = ProtoTransformer::transform(ast_program, &ctx, &mut field_lock);
+        field_lock.save(&lock_file)?;
+        TransformOutput::Single(transformed_code)
+      }
+    };
+
+    for diagnostic in ctx.take_diagnostics() {
+      eprintln!("Warning: {diagnostic}");
     }
+
+    Ok(transformed_output)
   }
 }
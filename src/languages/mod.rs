@@ -1,4 +1,9 @@
+pub mod go;
+pub mod java;
+pub mod json_schema;
 pub mod kotlin;
 pub mod language_factory;
+pub mod proto;
+pub mod python;
 pub mod shared;
 pub mod swift;
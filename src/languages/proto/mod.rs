@@ -0,0 +1,12 @@
+pub mod proto_field_lock;
+pub mod proto_field_type_trait;
+pub mod proto_imports;
+pub mod proto_is_service_type_trait;
+pub mod proto_message_type_trait;
+pub mod proto_style;
+pub mod proto_transformer;
+pub mod proto_type_trait;
+
+#[cfg(test)]
+#[path = "./proto_test.rs"]
+mod proto_test;
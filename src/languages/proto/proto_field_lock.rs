@@ -0,0 +1,89 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// Field and enum value numbers handed out in previous runs, persisted next to the
+/// generated `.proto` file so numbers never shift when members are reordered or removed
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProtoFieldLock {
+  pub messages: BTreeMap<String, BTreeMap<String, u32>>,
+  pub enums: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl ProtoFieldLock {
+  /// A missing lock file starts a fresh numbering
+  pub fn from_file(file_name: &str) -> Result<Self, String> {
+    let path = Path::new(file_name);
+    if !path.exists() {
+      return Ok(Self::default());
+    }
+
+    let lock_text = fs::read_to_string(path)
+      .map_err(|_| format!("Error: Unable to read proto lock file: {file_name}"))?;
+
+    serde_json::from_str(&lock_text)
+      .map_err(|err| format!("Error: Unable to parse proto lock file: {file_name}, {err}"))
+  }
+
+  pub fn save(&self, file_name: &str) -> Result<(), String> {
+    let lock_text = serde_json::to_string_pretty(self)
+      .map_err(|err| format!("Error: Unable to serialize proto lock file: {err}"))?;
+
+    fs::write(Path::new(file_name), format!("{lock_text}\n"))
+      .map_err(|_| format!("Error: Unable to write proto lock file: {file_name}"))
+  }
+
+  pub fn message_field_number(&mut self, message_name: &str, field_name: &str) -> u32 {
+    allocate_number(&mut self.messages, message_name, field_name)
+  }
+
+  /// Numbering starts at 1, as 0 is the `UNSPECIFIED` value
+  pub fn enum_value_number(&mut self, enum_name: &str, value_name: &str) -> u32 {
+    allocate_number(&mut self.enums, enum_name, value_name)
+  }
+
+  /// Numbers of fields that were removed from `message_name`, they must never be reused
+  pub fn reserved_field_numbers(&self, message_name: &str, field_names: &[String]) -> Vec<u32> {
+    removed_numbers(&self.messages, message_name, field_names)
+  }
+
+  /// Numbers of values that were removed from `enum_name`, they must never be reused
+  pub fn reserved_enum_numbers(&self, enum_name: &str, value_names: &[String]) -> Vec<u32> {
+    removed_numbers(&self.enums, enum_name, value_names)
+  }
+}
+
+fn removed_numbers(
+  scopes: &BTreeMap<String, BTreeMap<String, u32>>,
+  scope_name: &str,
+  names: &[String],
+) -> Vec<u32> {
+  scopes
+    .get(scope_name)
+    .map(|numbers| {
+      let mut numbers = numbers
+        .iter()
+        .filter(|(name, _)| !names.contains(name))
+        .map(|(_, number)| *number)
+        .collect::<Vec<_>>();
+      numbers.sort();
+      numbers
+    })
+    .unwrap_or_default()
+}
+
+fn allocate_number(
+  scopes: &mut BTreeMap<String, BTreeMap<String, u32>>,
+  scope_name: &str,
+  name: &str,
+) -> u32 {
+  let numbers = scopes.entry(scope_name.to_string()).or_default();
+  if let Some(number) = numbers.get(name) {
+    return *number;
+  }
+
+  let next_number = numbers.values().max().copied().unwrap_or_default() + 1;
+  numbers.insert(name.to_string(), next_number);
+  next_number
+}
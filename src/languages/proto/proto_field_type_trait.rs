@@ -0,0 +1,66 @@
+use oxc_ast::ast::{TSType, TSTypeReference};
+
+use crate::languages::{
+  proto::{proto_imports::import_proto_type, proto_type_trait::ProtoType},
+  shared::{stream_types::STREAM_TYPE_NAMES, transform_context::TransformContext},
+};
+
+/// Scalar types that are valid `map` keys
+const PROTO_MAP_KEY_TYPES: [&str; 4] = ["string", "int32", "int64", "bool"];
+
+pub trait ProtoFieldType {
+  /// Type of a message field, including the `repeated` label or `map<K, V>` form
+  fn to_proto_field_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl ProtoFieldType for TSTypeReference<'_> {
+  fn to_proto_field_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.type_name.to_string();
+    let type_param = |index: usize| {
+      self
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.get(index))
+        .map(|x| x.to_proto_type(ctx))
+        .unwrap_or_else(|| import_proto_type(ctx, "google.protobuf.Value"))
+    };
+
+    match type_name.as_str() {
+      "Promise" => self
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.first())
+        .map(|x| x.to_proto_field_type(ctx))
+        .unwrap_or_else(|| import_proto_type(ctx, "google.protobuf.Value")),
+
+      // a stream out of an rpc result holds its values as a list
+      "Array" | "ReadonlyArray" | "Set" | "ReadonlySet" => format!("repeated {}", type_param(0)),
+      name if STREAM_TYPE_NAMES.contains(&name) => format!("repeated {}", type_param(0)),
+
+      "Record" | "Map" | "ReadonlyMap" => {
+        let key_type = type_param(0);
+        let key_type = if PROTO_MAP_KEY_TYPES.contains(&key_type.as_str()) {
+          key_type
+        } else {
+          "string".to_string()
+        };
+        format!("map<{key_type}, {}>", type_param(1))
+      }
+
+      _ => self.to_proto_type(ctx),
+    }
+  }
+}
+
+impl ProtoFieldType for TSType<'_> {
+  fn to_proto_field_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSTypeReference(ref_type) => ref_type.to_proto_field_type(ctx),
+      TSType::TSArrayType(array_type) => {
+        format!("repeated {}", array_type.element_type.to_proto_type(ctx))
+      }
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_proto_field_type(ctx),
+      _ => self.to_proto_type(ctx),
+    }
+  }
+}
@@ -0,0 +1,18 @@
+use crate::languages::shared::transform_context::TransformContext;
+
+/// Well-known types and the file they are imported from
+pub const PROTO_IMPORTS: [(&str, &str); 4] = [
+  ("google.protobuf.ListValue", "google/protobuf/struct.proto"),
+  ("google.protobuf.Struct", "google/protobuf/struct.proto"),
+  (
+    "google.protobuf.Timestamp",
+    "google/protobuf/timestamp.proto",
+  ),
+  ("google.protobuf.Value", "google/protobuf/struct.proto"),
+];
+
+/// `type_name` of `PROTO_IMPORTS`, recording the import, the full name never collides
+/// with a declaration of the file
+pub fn import_proto_type(ctx: &TransformContext, type_name: &str) -> String {
+  ctx.import_type(type_name, type_name)
+}
@@ -0,0 +1,494 @@
+use std::collections::HashSet;
+
+use oxc_ast::ast::{
+  BindingPatternKind, FormalParameters, PropertyKey, TSEnumDeclaration, TSEnumMemberName,
+  TSInterfaceDeclaration, TSSignature, TSType,
+};
+use oxc_span::GetSpan;
+
+use crate::{
+  languages::{
+    proto::{
+      proto_field_lock::ProtoFieldLock, proto_field_type_trait::ProtoFieldType,
+      proto_imports::import_proto_type, proto_style,
+    },
+    shared::{
+      enum_values::{EnumValue, get_enum_values_or_report},
+      identifier::to_valid_identifier,
+      stream_types::STREAM_TYPE_NAMES,
+      transform_context::TransformContext,
+    },
+  },
+  utils::naming_utils::{to_pascal_case, to_snake_case},
+};
+
+use super::proto_is_service_type_trait::ProtoIsServiceType;
+
+pub trait ProtoMessageType {
+  /// Message, service or enum definitions, numbered through `field_lock`
+  fn to_proto_message_type(
+    &self,
+    ctx: &TransformContext,
+    field_lock: &mut ProtoFieldLock,
+  ) -> String;
+}
+
+struct ProtoField {
+  /// TypeScript name, used as the lock key
+  key: String,
+  field_type: String,
+  optional: bool,
+}
+
+struct ProtoRpc {
+  name: String,
+  request_fields: Vec<ProtoField>,
+  response_fields: Vec<ProtoField>,
+  /// The result is an async stream, the response message is sent once per value
+  server_streaming: bool,
+}
+
+/// The TS key, quoted keys are kept as they are and computed keys are only known at runtime
+fn to_proto_property_key(
+  ctx: &TransformContext,
+  owner_name: &str,
+  key: &PropertyKey,
+) -> Option<String> {
+  let key_name = key.static_name();
+  if key_name.is_none() {
+    ctx.report(format!(
+      "`{owner_name}` has a computed key `{}`, the member is left out",
+      ctx.source_text(key.span())
+    ));
+  }
+  key_name.map(|name| name.to_string())
+}
+
+/// A TS key as a `snake_case` field name, which starts with a letter,
+/// `content-type` -> `content_type`, `1st` -> `field_1st`
+fn to_proto_field_name(key: &str) -> String {
+  let field_name = to_snake_case(&to_valid_identifier(key));
+  let field_name = field_name.trim_start_matches('_');
+  if field_name.starts_with(|x: char| x.is_ascii_alphabetic()) {
+    field_name.to_string()
+  } else {
+    format!("field_{field_name}")
+  }
+}
+
+/// The JSON key protobuf derives from a field name, `content_type` -> `contentType`
+fn to_proto_json_name(field_name: &str) -> String {
+  let mut json_name = String::new();
+  let mut is_word_start = false;
+  for char in field_name.chars() {
+    if char == '_' {
+      is_word_start = true;
+    } else if is_word_start {
+      json_name.extend(char.to_uppercase());
+      is_word_start = false;
+    } else {
+      json_name.push(char);
+    }
+  }
+  json_name
+}
+
+fn to_proto_message(
+  message_name: &str,
+  fields: &[ProtoField],
+  field_lock: &mut ProtoFieldLock,
+) -> String {
+  let field_keys = fields
+    .iter()
+    .map(|field| field.key.clone())
+    .collect::<Vec<_>>();
+  let reserved_numbers = field_lock.reserved_field_numbers(message_name, &field_keys);
+
+  let mut body_data = fields
+    .iter()
+    .map(|field| {
+      let field_number = field_lock.message_field_number(message_name, &field.key);
+      let is_labelled =
+        field.field_type.starts_with("repeated ") || field.field_type.starts_with("map<");
+      let optional = if field.optional && !is_labelled {
+        "optional "
+      } else {
+        ""
+      };
+
+      // the JSON key stays the TS key
+      let field_name = to_proto_field_name(&field.key);
+      let json_name = if to_proto_json_name(&field_name) == field.key {
+        "".to_string()
+      } else {
+        format!(" [json_name = \"{}\"]", field.key)
+      };
+
+      format!(
+        "{}{}{} {} = {}{};",
+        proto_style::INDENT_SPACE,
+        optional,
+        field.field_type,
+        field_name,
+        field_number,
+        json_name
+      )
+    })
+    .collect::<Vec<_>>();
+
+  if !reserved_numbers.is_empty() {
+    let reserved_list = reserved_numbers
+      .iter()
+      .map(|x| x.to_string())
+      .collect::<Vec<_>>()
+      .join(", ");
+    body_data.insert(
+      0,
+      format!("{}reserved {};", proto_style::INDENT_SPACE, reserved_list),
+    );
+  }
+
+  if body_data.is_empty() {
+    format!("message {message_name} {{\n}}\n\n")
+  } else {
+    format!(
+      "message {message_name} {{\n{}\n}}\n\n",
+      body_data.join("\n")
+    )
+  }
+}
+
+fn to_proto_request_fields(params: &FormalParameters, ctx: &TransformContext) -> Vec<ProtoField> {
+  params
+    .items
+    .iter()
+    .map(|param| ProtoField {
+      key: match &param.pattern.kind {
+        BindingPatternKind::BindingIdentifier(val) => val.name.to_string(),
+        _ => "unknown_binding_pattern".to_owned(),
+      },
+      field_type: param
+        .pattern
+        .type_annotation
+        .as_ref()
+        .map(|t| t.type_annotation.to_proto_field_type(ctx))
+        .unwrap_or_else(|| import_proto_type(ctx, "google.protobuf.Value")),
+      optional: param.pattern.optional,
+    })
+    .collect()
+}
+
+/// `void` results get an empty response message, anything else is wrapped in a `value` field
+fn to_proto_response_fields(
+  return_type: Option<&TSType>,
+  ctx: &TransformContext,
+) -> Vec<ProtoField> {
+  let return_type = match return_type {
+    Some(TSType::TSTypeReference(ref_type)) if ref_type.type_name.to_string() == "Promise" => {
+      ref_type
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.first())
+    }
+    _ => return_type,
+  };
+
+  match return_type {
+    None | Some(TSType::TSVoidKeyword(_)) => vec![],
+    Some(return_type) => vec![ProtoField {
+      key: "value".to_string(),
+      field_type: return_type.to_proto_field_type(ctx),
+      optional: false,
+    }],
+  }
+}
+
+/// An rpc returning `return_type`, an `AsyncIterable<T>` result streams a response per `T`
+fn to_proto_rpc(
+  rpc_name: String,
+  request_fields: Vec<ProtoField>,
+  return_type: Option<&TSType>,
+  ctx: &TransformContext,
+) -> ProtoRpc {
+  let stream_type = match return_type {
+    Some(TSType::TSTypeReference(ref_type))
+      if STREAM_TYPE_NAMES.contains(&ref_type.type_name.to_string().as_str()) =>
+    {
+      Some(
+        ref_type
+          .type_parameters
+          .as_ref()
+          .and_then(|x| x.params.first()),
+      )
+    }
+    _ => None,
+  };
+
+  ProtoRpc {
+    name: rpc_name,
+    request_fields,
+    response_fields: to_proto_response_fields(stream_type.unwrap_or(return_type), ctx),
+    server_streaming: stream_type.is_some(),
+  }
+}
+
+impl ProtoMessageType for TSInterfaceDeclaration<'_> {
+  fn to_proto_message_type(
+    &self,
+    ctx: &TransformContext,
+    field_lock: &mut ProtoFieldLock,
+  ) -> String {
+    let interface_name = self.id.name.to_string();
+
+    if !self.is_proto_service_type() {
+      let fields = self
+        .body
+        .body
+        .iter()
+        .filter_map(|signature| match signature {
+          TSSignature::TSPropertySignature(prop_sig) => {
+            let type_annotation = prop_sig
+              .type_annotation
+              .as_ref()
+              .map(|annotation| &annotation.type_annotation);
+
+            if matches!(type_annotation, Some(TSType::TSFunctionType(_))) {
+              return None;
+            }
+
+            Some(ProtoField {
+              key: to_proto_property_key(ctx, &interface_name, &prop_sig.key)?,
+              field_type: type_annotation
+                .map(|x| x.to_proto_field_type(ctx))
+                .unwrap_or_else(|| import_proto_type(ctx, "google.protobuf.Value")),
+              optional: prop_sig.optional,
+            })
+          }
+          _ => None,
+        })
+        .collect::<Vec<_>>();
+
+      return to_proto_message(&interface_name, &fields, field_lock);
+    }
+
+    let mut rpcs: Vec<ProtoRpc> = vec![];
+
+    for signature in &self.body.body {
+      match signature {
+        TSSignature::TSMethodSignature(method_sig) => {
+          let Some(method_name) = to_proto_property_key(ctx, &interface_name, &method_sig.key)
+          else {
+            continue;
+          };
+          rpcs.push(to_proto_rpc(
+            to_pascal_case(&to_valid_identifier(&method_name)),
+            to_proto_request_fields(&method_sig.params, ctx),
+            method_sig.return_type.as_ref().map(|r| &r.type_annotation),
+            ctx,
+          ));
+        }
+        TSSignature::TSPropertySignature(prop_sig) => {
+          let Some(prop_name) = to_proto_property_key(ctx, &interface_name, &prop_sig.key) else {
+            continue;
+          };
+          let prop_rpc_name = to_pascal_case(&to_valid_identifier(&prop_name));
+          let type_annotation = prop_sig
+            .type_annotation
+            .as_ref()
+            .map(|annotation| &annotation.type_annotation);
+
+          // If property is a arrow function
+          if let Some(TSType::TSFunctionType(fn_type)) = type_annotation {
+            rpcs.push(to_proto_rpc(
+              prop_rpc_name,
+              to_proto_request_fields(&fn_type.params, ctx),
+              Some(&fn_type.return_type.type_annotation),
+              ctx,
+            ));
+            continue;
+          }
+
+          rpcs.push(to_proto_rpc(
+            format!("Get{prop_rpc_name}"),
+            vec![],
+            type_annotation,
+            ctx,
+          ));
+
+          if !prop_sig.readonly {
+            let mut value_fields = to_proto_response_fields(type_annotation, ctx);
+            for field in &mut value_fields {
+              field.optional = prop_sig.optional;
+            }
+            rpcs.push(ProtoRpc {
+              name: format!("Set{prop_rpc_name}"),
+              request_fields: value_fields,
+              response_fields: vec![],
+              server_streaming: false,
+            });
+          }
+        }
+        _ => {}
+      }
+    }
+
+    // messages are named after the service as well, `GetUser` of two services must not collide
+    let mut rpc_names = HashSet::new();
+    for ProtoRpc { name: rpc_name, .. } in &rpcs {
+      if !rpc_names.insert(rpc_name) {
+        ctx.report(format!(
+          "`{interface_name}` declares the rpc `{rpc_name}` more than once"
+        ));
+      }
+      for message_name in [
+        format!("{interface_name}{rpc_name}Request"),
+        format!("{interface_name}{rpc_name}Response"),
+      ] {
+        if ctx.is_declared(&message_name) {
+          ctx.report(format!(
+            "`{interface_name}.{rpc_name}` generates the message `{message_name}`, which is already declared"
+          ));
+        }
+      }
+    }
+
+    let rpc_lines = rpcs
+      .iter()
+      .map(|rpc| {
+        let rpc_name = &rpc.name;
+        let stream = if rpc.server_streaming { "stream " } else { "" };
+        format!(
+          "{}rpc {rpc_name}({interface_name}{rpc_name}Request) returns ({stream}{interface_name}{rpc_name}Response);",
+          proto_style::INDENT_SPACE
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    let messages = rpcs
+      .iter()
+      .map(|rpc| {
+        format!(
+          "{}{}",
+          to_proto_message(
+            &format!("{interface_name}{}Request", rpc.name),
+            &rpc.request_fields,
+            field_lock
+          ),
+          to_proto_message(
+            &format!("{interface_name}{}Response", rpc.name),
+            &rpc.response_fields,
+            field_lock
+          )
+        )
+      })
+      .collect::<String>();
+
+    format!("service {interface_name} {{\n{rpc_lines}\n}}\n\n{messages}")
+  }
+}
+
+impl ProtoMessageType for TSEnumDeclaration<'_> {
+  /// TypeScript integer values, computed the way TypeScript does, are kept as they are, other
+  /// values are numbered through the lock and the numbers of removed values are reserved.
+  /// proto3 requires a zero value, `<NAME>_UNSPECIFIED` is added when there is none
+  fn to_proto_message_type(
+    &self,
+    ctx: &TransformContext,
+    field_lock: &mut ProtoFieldLock,
+  ) -> String {
+    let enum_name = self.id.to_string();
+    let value_prefix = to_snake_case(&enum_name).to_uppercase();
+
+    // enum values are `int32`
+    let integer_values = get_enum_values_or_report(ctx, self, &enum_name).and_then(|values| {
+      values
+        .iter()
+        .map(|x| match x {
+          EnumValue::Number(value)
+            if value.fract() == 0.0 && *value >= i32::MIN as f64 && *value <= i32::MAX as f64 =>
+          {
+            Some(*value as i64)
+          }
+          _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+    });
+
+    let member_names = self
+      .members
+      .iter()
+      .map(|x| match &x.id {
+        TSEnumMemberName::Identifier(enum_id) => enum_id.name.to_string(),
+        TSEnumMemberName::String(enum_string) => enum_string.value.to_string(),
+      })
+      .collect::<Vec<_>>();
+
+    let mut enum_values = member_names
+      .iter()
+      .enumerate()
+      .map(|(index, member_name)| {
+        let value_number = match &integer_values {
+          Some(values) => values[index],
+          None => field_lock.enum_value_number(&enum_name, member_name) as i64,
+        };
+
+        let value_name = format!(
+          "{value_prefix}_{}",
+          to_snake_case(&to_valid_identifier(member_name)).to_uppercase()
+        );
+        (value_name, value_number)
+      })
+      .collect::<Vec<_>>();
+
+    if !enum_values
+      .iter()
+      .any(|(_, value_number)| *value_number == 0)
+    {
+      enum_values.push((format!("{value_prefix}_UNSPECIFIED"), 0));
+    }
+    enum_values.sort_by_key(|(_, value_number)| *value_number);
+
+    let mut enum_lines = enum_values
+      .iter()
+      .map(|(value_name, value_number)| {
+        format!(
+          "{}{value_name} = {value_number};",
+          proto_style::INDENT_SPACE
+        )
+      })
+      .collect::<Vec<_>>();
+
+    // locked numbers taken by integer values again are not reserved
+    let reserved_numbers = field_lock
+      .reserved_enum_numbers(&enum_name, &member_names)
+      .into_iter()
+      .filter(|number| {
+        !enum_values
+          .iter()
+          .any(|(_, value_number)| *value_number == *number as i64)
+      })
+      .map(|number| number.to_string())
+      .collect::<Vec<_>>();
+    if !reserved_numbers.is_empty() {
+      enum_lines.insert(
+        0,
+        format!(
+          "{}reserved {};",
+          proto_style::INDENT_SPACE,
+          reserved_numbers.join(", ")
+        ),
+      );
+    }
+    // members sharing a value, `Default = Low`, are aliases
+    if enum_values.windows(2).any(|pair| pair[0].1 == pair[1].1) {
+      enum_lines.insert(
+        0,
+        format!("{}option allow_alias = true;", proto_style::INDENT_SPACE),
+      );
+    }
+    let enum_lines = enum_lines.join("\n");
+
+    format!("enum {enum_name} {{\n{enum_lines}\n}}\n\n")
+  }
+}
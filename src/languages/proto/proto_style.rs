@@ -0,0 +1 @@
+pub const INDENT_SPACE: &str = "  ";
@@ -0,0 +1,373 @@
+#[cfg(test)]
+mod tests {
+
+  use oxc_allocator::Allocator;
+  use oxc_parser::{ParseOptions, Parser};
+  use oxc_span::SourceType;
+
+  use crate::{
+    config::transform_config::TransformConfig,
+    languages::{
+      proto::{proto_field_lock::ProtoFieldLock, proto_transformer::ProtoTransformer},
+      shared::transform_context::TransformContext,
+    },
+  };
+
+  fn transform(source_text: &str, field_lock: &mut ProtoFieldLock) -> String {
+    transform_with_diagnostics(source_text, field_lock).0
+  }
+
+  fn transform_with_diagnostics(
+    source_text: &str,
+    field_lock: &mut ProtoFieldLock,
+  ) -> (String, Vec<String>) {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, source_text, source_type)
+      .with_options(ParseOptions {
+        ..ParseOptions::default()
+      })
+      .parse();
+
+    let program = parser_ret.program;
+    let config = TransformConfig::default();
+    let ctx = TransformContext::new(&program, &config);
+    let transformed_code = ProtoTransformer::transform(&program, &ctx, field_lock);
+    (transformed_code, ctx.take_diagnostics())
+  }
+
+  #[test]
+  fn it_converts_to_proto_type() {
+    let source_text = r#"
+    export interface Contract {
+      getUser(userId: string): Promise<User>;
+    }
+
+    interface User {
+      readonly name: string;
+      age?: number;
+      tags: string[];
+      scores: Record<string, number>;
+    }
+
+    enum Currency {
+      USD = "usd",
+      EUR = "eur"
+    }
+    "#;
+
+    let mut field_lock = ProtoFieldLock::default();
+    let transformed_code = transform(source_text, &mut field_lock);
+    println!("{transformed_code}");
+
+    let expected_code = r#"syntax = "proto3";
+
+service Contract {
+  rpc GetUser(ContractGetUserRequest) returns (ContractGetUserResponse);
+}
+
+message ContractGetUserRequest {
+  string user_id = 1;
+}
+
+message ContractGetUserResponse {
+  User value = 1;
+}
+
+message User {
+  string name = 1;
+  optional double age = 2;
+  repeated string tags = 3;
+  map<string, double> scores = 4;
+}
+
+enum Currency {
+  CURRENCY_UNSPECIFIED = 0;
+  CURRENCY_USD = 1;
+  CURRENCY_EUR = 2;
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_keeps_proto_field_numbers_stable() {
+    let mut field_lock = ProtoFieldLock::default();
+    transform(
+      "interface User { name: string; email: string; age: number; }",
+      &mut field_lock,
+    );
+
+    // reordered, `email` removed and `nickName` added
+    let transformed_code = transform(
+      "interface User { age: number; nickName: string; name: string; }",
+      &mut field_lock,
+    );
+
+    let expected_code = r#"syntax = "proto3";
+
+message User {
+  reserved 2;
+  double age = 3;
+  string nick_name = 4;
+  string name = 1;
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_names_rpc_messages_after_their_service() {
+    let source_text = r#"
+    interface UserService {
+      get(id: string): User;
+    }
+
+    interface OrderService {
+      get(id: string): Order;
+    }
+
+    interface User {
+      readonly name: string;
+    }
+
+    interface Order {
+      readonly total: number;
+    }
+    "#;
+
+    let mut field_lock = ProtoFieldLock::default();
+    let (transformed_code, diagnostics) = transform_with_diagnostics(source_text, &mut field_lock);
+
+    let expected_code = r#"syntax = "proto3";
+
+service UserService {
+  rpc Get(UserServiceGetRequest) returns (UserServiceGetResponse);
+}
+
+message UserServiceGetRequest {
+  string id = 1;
+}
+
+message UserServiceGetResponse {
+  User value = 1;
+}
+
+service OrderService {
+  rpc Get(OrderServiceGetRequest) returns (OrderServiceGetResponse);
+}
+
+message OrderServiceGetRequest {
+  string id = 1;
+}
+
+message OrderServiceGetResponse {
+  Order value = 1;
+}
+
+message User {
+  string name = 1;
+}
+
+message Order {
+  double total = 1;
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn it_reports_rpc_message_collisions() {
+    let source_text = r#"
+    interface Contract {
+      getName(): string;
+      readonly name: string;
+      ping(): void;
+    }
+
+    interface ContractPingRequest {
+      readonly at: number;
+    }
+    "#;
+
+    let mut field_lock = ProtoFieldLock::default();
+    let (_, diagnostics) = transform_with_diagnostics(source_text, &mut field_lock);
+
+    assert_eq!(
+      diagnostics,
+      vec![
+        "`Contract` declares the rpc `GetName` more than once",
+        "`Contract.Ping` generates the message `ContractPingRequest`, which is already declared"
+      ]
+    );
+  }
+
+  #[test]
+  fn it_reserves_removed_enum_values() {
+    let mut field_lock = ProtoFieldLock::default();
+    transform(
+      r#"enum Currency { USD = "usd", EUR = "eur", GBP = "gbp" }"#,
+      &mut field_lock,
+    );
+
+    // `EUR` removed and `JPY` added
+    let transformed_code = transform(
+      r#"enum Currency { USD = "usd", GBP = "gbp", JPY = "jpy" }"#,
+      &mut field_lock,
+    );
+
+    let expected_code = r#"syntax = "proto3";
+
+enum Currency {
+  reserved 2;
+  CURRENCY_UNSPECIFIED = 0;
+  CURRENCY_USD = 1;
+  CURRENCY_GBP = 3;
+  CURRENCY_JPY = 4;
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_keeps_quoted_keys_as_json_names() {
+    let source_text = r#"
+    const field = "id";
+
+    interface Headers {
+      "content-type": string;
+      "1st"?: string;
+      user_id: string;
+      requestId: string;
+      [field]: string;
+    }
+    "#;
+
+    let mut field_lock = ProtoFieldLock::default();
+    let (transformed_code, diagnostics) = transform_with_diagnostics(source_text, &mut field_lock);
+
+    let expected_code = r#"syntax = "proto3";
+
+message Headers {
+  string content_type = 1 [json_name = "content-type"];
+  optional string field_1st = 2 [json_name = "1st"];
+  string user_id = 3 [json_name = "user_id"];
+  string request_id = 4;
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`Headers` has a computed key `field`, the member is left out"]
+    );
+    assert_eq!(
+      field_lock.messages["Headers"].keys().collect::<Vec<_>>(),
+      vec!["1st", "content-type", "requestId", "user_id"]
+    );
+  }
+
+  #[test]
+  fn it_computes_enum_values() {
+    let source_text = r#"
+    enum Color {
+      Red = 5,
+      Green,
+    }
+
+    enum Offset {
+      Before = -1,
+      Same,
+      After,
+    }
+
+    enum Permission {
+      None,
+      Read = 1 << 0,
+      Write = 1 << 1,
+      ReadWrite = Read | Write,
+      All = ReadWrite,
+    }
+    "#;
+
+    let mut field_lock = ProtoFieldLock::default();
+    let transformed_code = transform(source_text, &mut field_lock);
+
+    let expected_code = r#"syntax = "proto3";
+
+enum Color {
+  COLOR_UNSPECIFIED = 0;
+  COLOR_RED = 5;
+  COLOR_GREEN = 6;
+}
+
+enum Offset {
+  OFFSET_BEFORE = -1;
+  OFFSET_SAME = 0;
+  OFFSET_AFTER = 1;
+}
+
+enum Permission {
+  option allow_alias = true;
+  PERMISSION_NONE = 0;
+  PERMISSION_READ = 1;
+  PERMISSION_WRITE = 2;
+  PERMISSION_READ_WRITE = 3;
+  PERMISSION_ALL = 3;
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_well_known_types_and_reports_undeclared_ones() {
+    let source_text = r#"
+    import { Address } from "./address";
+
+    interface Event {
+      readonly createdAt: Date;
+      readonly payload: Uint8Array;
+      readonly size: bigint;
+      readonly address: Address;
+    }
+
+    interface Feed {
+      watch(topic: string): AsyncIterable<Event>;
+    }
+    "#;
+
+    let mut field_lock = ProtoFieldLock::default();
+    let (transformed_code, diagnostics) = transform_with_diagnostics(source_text, &mut field_lock);
+
+    let expected_code = r#"syntax = "proto3";
+
+import "google/protobuf/struct.proto";
+import "google/protobuf/timestamp.proto";
+
+message Event {
+  google.protobuf.Timestamp created_at = 1;
+  bytes payload = 2;
+  int64 size = 3;
+  google.protobuf.Value address = 4;
+}
+
+service Feed {
+  rpc Watch(FeedWatchRequest) returns (stream FeedWatchResponse);
+}
+
+message FeedWatchRequest {
+  string topic = 1;
+}
+
+message FeedWatchResponse {
+  Event value = 1;
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`Address` is not declared in this file, it is emitted as google.protobuf.Value"]
+    );
+  }
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+use oxc_ast::ast::{Declaration, Program, Statement};
+
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::{
+  proto_field_lock::ProtoFieldLock, proto_imports::PROTO_IMPORTS,
+  proto_message_type_trait::ProtoMessageType,
+};
+
+pub struct ProtoTransformer;
+
+impl ProtoTransformer {
+  pub fn transform(
+    ast_program: &Program,
+    ctx: &TransformContext,
+    field_lock: &mut ProtoFieldLock,
+  ) -> String {
+    let mut output = String::new();
+
+    for statement in &ast_program.body {
+      let declaration = match statement {
+        Statement::ExportNamedDeclaration(export_decl) => export_decl.declaration.as_ref(),
+        _ => statement.as_declaration(),
+      };
+
+      let statement_code = match declaration {
+        Some(Declaration::TSInterfaceDeclaration(interface_decl)) => {
+          interface_decl.to_proto_message_type(ctx, field_lock)
+        }
+        Some(Declaration::TSEnumDeclaration(enum_decl)) => {
+          enum_decl.to_proto_message_type(ctx, field_lock)
+        }
+        _ => continue,
+      };
+      output.push_str(&statement_code);
+    }
+
+    let mut header = String::from("syntax = \"proto3\";\n\n");
+    if let Some(package_name) = &ctx.config.proto.package {
+      header.push_str(&format!("package {package_name};\n\n"));
+    }
+    // Struct, Value and ListValue are all declared in struct.proto
+    let import_files = ctx
+      .take_imported_types()
+      .iter()
+      .filter_map(|type_name| {
+        PROTO_IMPORTS
+          .iter()
+          .find(|(name, _)| name == type_name)
+          .map(|(_, file_name)| *file_name)
+      })
+      .collect::<BTreeSet<_>>();
+    for file_name in import_files {
+      header.push_str(&format!("import \"{file_name}\";\n"));
+    }
+    if !header.ends_with("\n\n") {
+      header.push('\n');
+    }
+
+    format!("{header}{}\n", output.trim_end())
+  }
+}
@@ -0,0 +1,63 @@
+use oxc_ast::ast::{TSType, TSTypeReference};
+
+use crate::languages::{
+  proto::proto_imports::import_proto_type,
+  shared::{stream_types::STREAM_TYPE_NAMES, transform_context::TransformContext},
+};
+
+pub trait ProtoType {
+  fn to_proto_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl ProtoType for TSTypeReference<'_> {
+  fn to_proto_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.type_name.to_string();
+    match type_name.as_str() {
+      "Promise" => self
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.first())
+        .map(|x| x.to_proto_type(ctx))
+        .unwrap_or_else(|| import_proto_type(ctx, "google.protobuf.Value")),
+
+      // nested `repeated` and `map` are not allowed, the dynamic well-known types are used instead
+      "Array" | "ReadonlyArray" | "Set" | "ReadonlySet" => {
+        import_proto_type(ctx, "google.protobuf.ListValue")
+      }
+      "Record" | "Map" | "ReadonlyMap" => import_proto_type(ctx, "google.protobuf.Struct"),
+      name if STREAM_TYPE_NAMES.contains(&name) => {
+        import_proto_type(ctx, "google.protobuf.ListValue")
+      }
+
+      "Date" => import_proto_type(ctx, "google.protobuf.Timestamp"),
+      "Uint8Array" | "ArrayBuffer" => "bytes".to_string(),
+      "URL" => "string".to_string(),
+
+      _ if ctx.is_declared(&type_name) => type_name,
+      // proto has no generics, type params hold any value
+      _ if ctx.is_type_parameter(&type_name) => import_proto_type(ctx, "google.protobuf.Value"),
+      _ => {
+        ctx.report(format!(
+          "`{type_name}` is not declared in this file, it is emitted as google.protobuf.Value"
+        ));
+        import_proto_type(ctx, "google.protobuf.Value")
+      }
+    }
+  }
+}
+
+impl ProtoType for TSType<'_> {
+  fn to_proto_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSStringKeyword(_) => "string".to_string(),
+      TSType::TSNumberKeyword(_) => "double".to_string(),
+      TSType::TSBooleanKeyword(_) => "bool".to_string(),
+      TSType::TSBigIntKeyword(_) => "int64".to_string(),
+      TSType::TSObjectKeyword(_) => import_proto_type(ctx, "google.protobuf.Struct"),
+      TSType::TSTypeReference(ref_type) => ref_type.to_proto_type(ctx),
+      TSType::TSArrayType(_) => import_proto_type(ctx, "google.protobuf.ListValue"),
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_proto_type(ctx),
+      _ => import_proto_type(ctx, "google.protobuf.Value"),
+    }
+  }
+}
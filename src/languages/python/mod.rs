@@ -0,0 +1,12 @@
+pub mod python_class_type_trait;
+pub mod python_enum_display_type_trait;
+pub mod python_fn_return_type_trait;
+pub mod python_identifier;
+pub mod python_is_protocol_type_trait;
+pub mod python_style;
+pub mod python_transformer;
+pub mod python_type_trait;
+
+#[cfg(test)]
+#[path = "./python_test.rs"]
+mod python_test;
@@ -0,0 +1,154 @@
+use oxc_ast::ast::{TSInterfaceDeclaration, TSSignature};
+
+use crate::{
+  config::transform_config::PythonModelStyle,
+  languages::{
+    python::{
+      python_style,
+      python_type_trait::{PythonType, to_python_member_name},
+    },
+    shared::transform_context::TransformContext,
+  },
+};
+
+pub trait PythonClassType {
+  fn to_python_class_type(&self, ctx: &TransformContext) -> String;
+}
+
+struct PythonField {
+  name: String,
+  /// Original TypeScript key, kept when it differs from the snake_case name
+  alias: Option<String>,
+  type_name: String,
+  optional: bool,
+}
+
+impl PythonClassType for TSInterfaceDeclaration<'_> {
+  fn to_python_class_type(&self, ctx: &TransformContext) -> String {
+    let class_name = ctx.declared_type_name(&self.id.name, "python");
+    let fields = self
+      .body
+      .body
+      .iter()
+      .filter_map(|signature| match signature {
+        TSSignature::TSPropertySignature(prop_sig) => {
+          let key = prop_sig.key.to_python_type(ctx);
+          let name = to_python_member_name(&key, ctx.config.python.naming.properties);
+          let type_name = prop_sig
+            .type_annotation
+            .as_ref()
+            .map(|annotation| annotation.type_annotation.to_python_type(ctx))
+            .unwrap_or_else(|| "Any".to_string());
+
+          Some(PythonField {
+            alias: (name != key).then_some(key),
+            name,
+            type_name: if prop_sig.optional {
+              format!("Optional[{type_name}]")
+            } else {
+              type_name
+            },
+            optional: prop_sig.optional,
+          })
+        }
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    match ctx.config.python.model_style {
+      PythonModelStyle::Dataclass => to_python_dataclass(&class_name, &fields),
+      PythonModelStyle::Pydantic => to_python_pydantic_model(&class_name, &fields),
+    }
+  }
+}
+
+fn to_python_dataclass(class_name: &str, fields: &[PythonField]) -> String {
+  let indent = python_style::INDENT_SPACE;
+  let mut body_data = fields
+    .iter()
+    .map(|field| {
+      let default_value = if field.optional { " = None" } else { "" };
+      format!("{indent}{}: {}{default_value}", field.name, field.type_name)
+    })
+    .collect::<Vec<_>>();
+
+  // dataclasses have no aliases, the renamed fields are mapped to their keys by hand
+  if fields.iter().any(|field| field.alias.is_some()) {
+    let indent2 = indent.repeat(2);
+    let indent3 = indent.repeat(3);
+    let key = |field: &PythonField| field.alias.clone().unwrap_or_else(|| field.name.clone());
+    let dict_entries = fields
+      .iter()
+      .map(|field| format!("{indent3}\"{}\": self.{},\n", key(field), field.name))
+      .collect::<String>();
+    let init_args = fields
+      .iter()
+      .map(|field| {
+        if field.optional {
+          format!("{indent3}{}=data.get(\"{}\"),\n", field.name, key(field))
+        } else {
+          format!("{indent3}{}=data[\"{}\"],\n", field.name, key(field))
+        }
+      })
+      .collect::<String>();
+
+    body_data.push(format!(
+      "\n{indent}def to_dict(self) -> dict[str, Any]:\n\
+      {indent2}return {{\n{dict_entries}{indent2}}}\n\n\
+      {indent}@classmethod\n\
+      {indent}def from_dict(cls, data: dict[str, Any]) -> {class_name}:\n\
+      {indent2}return cls(\n{init_args}{indent2})"
+    ));
+  }
+
+  format!(
+    "@dataclass(kw_only=True)\nclass {class_name}:\n{}\n",
+    to_python_class_body(&body_data)
+  )
+}
+
+fn to_python_pydantic_model(class_name: &str, fields: &[PythonField]) -> String {
+  let mut body_data = fields
+    .iter()
+    .map(|field| {
+      let field_value = match (&field.alias, field.optional) {
+        (Some(alias), true) => format!(" = Field(default=None, alias=\"{alias}\")"),
+        (Some(alias), false) => format!(" = Field(alias=\"{alias}\")"),
+        (None, true) => " = None".to_string(),
+        (None, false) => "".to_string(),
+      };
+
+      format!(
+        "{}{}: {}{}",
+        python_style::INDENT_SPACE,
+        field.name,
+        field.type_name,
+        field_value
+      )
+    })
+    .collect::<Vec<_>>();
+
+  // aliases are used for parsing, allow the snake_case names as well
+  if fields.iter().any(|field| field.alias.is_some()) {
+    body_data.insert(
+      0,
+      format!(
+        "{}model_config = ConfigDict(populate_by_name=True)\n",
+        python_style::INDENT_SPACE
+      ),
+    );
+  }
+
+  format!(
+    "class {class_name}(BaseModel):\n{}\n",
+    to_python_class_body(&body_data)
+  )
+}
+
+fn to_python_class_body(body_data: &[String]) -> String {
+  if body_data.is_empty() {
+    format!("{}pass", python_style::INDENT_SPACE)
+  } else {
+    body_data.join("\n")
+  }
+}
@@ -0,0 +1,31 @@
+use oxc_ast::ast::TSEnumDeclaration;
+
+use crate::languages::shared::enum_values::{EnumValue, EnumValues};
+
+pub trait PythonEnumDisplayType {
+  /// Base class of the generated enum, `StrEnum`, `IntEnum` or `Enum`
+  fn to_python_enum_display_type(&self) -> String;
+}
+
+impl PythonEnumDisplayType for TSEnumDeclaration<'_> {
+  fn to_python_enum_display_type(&self) -> String {
+    let values = self.get_enum_values();
+    let all_enum_string = values
+      .iter()
+      .all(|x| matches!(x, Some(EnumValue::String(_))));
+
+    if all_enum_string {
+      return "StrEnum".to_string();
+    }
+
+    let all_enum_int = values
+      .iter()
+      .all(|x| matches!(x, Some(EnumValue::Number(value)) if value.fract() == 0.0));
+
+    if all_enum_int {
+      return "IntEnum".to_string();
+    }
+
+    "Enum".to_string()
+  }
+}
@@ -0,0 +1,24 @@
+use oxc_ast::ast::TSType;
+
+use crate::languages::shared::{is_async_trait::IsAsyncType, transform_context::TransformContext};
+
+use super::python_type_trait::PythonType;
+
+pub trait PythonFunctionReturnType {
+  /// Return annotation of a `def`, `Promise<T>` is unwrapped as it is returned from an `async def`
+  fn to_python_fn_return_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl PythonFunctionReturnType for TSType<'_> {
+  fn to_python_fn_return_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSTypeReference(val) if val.is_async_type() => val
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.first())
+        .map(|x| x.to_python_type(ctx))
+        .unwrap_or_else(|| "Any".into()),
+      _ => self.to_python_type(ctx),
+    }
+  }
+}
@@ -0,0 +1,14 @@
+use crate::languages::shared::identifier::{suffix_reserved_word, to_valid_identifier};
+
+/// Keywords that cannot be used as a name, soft keywords such as `match` can
+const PYTHON_RESERVED_WORDS: &[&str] = &[
+  "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+  "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+  "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with",
+  "yield",
+];
+
+/// A TS name as a Python identifier, sanitized and suffixed, `from` -> `from_`
+pub fn to_python_identifier(name: &str) -> String {
+  suffix_reserved_word(&to_valid_identifier(name), PYTHON_RESERVED_WORDS)
+}
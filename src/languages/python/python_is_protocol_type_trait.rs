@@ -0,0 +1,21 @@
+use oxc_ast::ast::{TSInterfaceDeclaration, TSSignature, TSType};
+
+pub trait PythonIsProtocolType {
+  fn is_python_protocol_type(&self) -> bool;
+}
+
+impl PythonIsProtocolType for TSInterfaceDeclaration<'_> {
+  fn is_python_protocol_type(&self) -> bool {
+    self.body.body.iter().any(|x| match x {
+      TSSignature::TSMethodSignature(_) => true,
+      TSSignature::TSPropertySignature(prop_sig) => {
+        if let Some(type_annotation) = &prop_sig.type_annotation {
+          matches!(type_annotation.type_annotation, TSType::TSFunctionType(_))
+        } else {
+          false
+        }
+      }
+      _ => false,
+    })
+  }
+}
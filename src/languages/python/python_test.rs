@@ -0,0 +1,280 @@
+#[cfg(test)]
+mod tests {
+
+  use oxc_allocator::Allocator;
+  use oxc_parser::{ParseOptions, Parser};
+  use oxc_span::SourceType;
+
+  use crate::{
+    config::transform_config::{NamingConvention, PythonModelStyle, TransformConfig},
+    languages::{
+      python::python_transformer::PythonTransformer, shared::transform_context::TransformContext,
+    },
+  };
+
+  fn transform(source_text: &str, config: &TransformConfig) -> String {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, source_text, source_type)
+      .with_options(ParseOptions {
+        ..ParseOptions::default()
+      })
+      .parse();
+
+    let program = parser_ret.program;
+    PythonTransformer::transform(&program, &TransformContext::new(&program, config))
+  }
+
+  #[test]
+  fn it_converts_to_python_type() {
+    let source_text = r#"
+    export interface HelloWorld {
+      readonly color: string;
+      getUser(userId: string): Promise<User>;
+    }
+
+    interface User {
+      readonly name: string;
+      userId: string;
+      nickName?: string;
+    }
+
+    enum Currency {
+      USD = "usd",
+      EUR = "eur"
+    }
+    "#;
+
+    let transformed_code = transform(source_text, &TransformConfig::default());
+    println!("{transformed_code}");
+
+    let expected_code = r#"from __future__ import annotations
+
+from dataclasses import dataclass
+from enum import StrEnum
+from typing import Any, Optional, Protocol
+
+
+class HelloWorld(Protocol):
+    @property
+    def color(self) -> str: ...
+    async def get_user(self, user_id: str) -> User: ...
+
+
+@dataclass(kw_only=True)
+class User:
+    name: str
+    user_id: str
+    nick_name: Optional[str] = None
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "name": self.name,
+            "userId": self.user_id,
+            "nickName": self.nick_name,
+        }
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> User:
+        return cls(
+            name=data["name"],
+            user_id=data["userId"],
+            nick_name=data.get("nickName"),
+        )
+
+
+class Currency(StrEnum):
+    USD = "usd"
+    EUR = "eur"
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_to_pydantic_model() {
+    let source_text = r#"
+    interface User {
+      readonly name: string;
+      userId?: number;
+    }
+    "#;
+
+    let mut config = TransformConfig::default();
+    config.python.model_style = PythonModelStyle::Pydantic;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"from __future__ import annotations
+
+from typing import Optional
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class User(BaseModel):
+    model_config = ConfigDict(populate_by_name=True)
+
+    name: str
+    user_id: Optional[float] = Field(default=None, alias="userId")
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_naming_conventions() {
+    let source_text = r#"
+    interface userProfile {
+      readonly displayName: string;
+      readonly status: accountStatus;
+    }
+
+    enum accountStatus {
+      active = "active",
+      suspended = "suspended",
+    }
+    "#;
+
+    let mut config = TransformConfig::default();
+    config.python.naming.types = Some(NamingConvention::PascalCase);
+    config.python.naming.enum_cases = Some(NamingConvention::ScreamingSnakeCase);
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"from __future__ import annotations
+
+from dataclasses import dataclass
+from enum import StrEnum
+from typing import Any
+
+
+@dataclass(kw_only=True)
+class UserProfile:
+    display_name: str
+    status: AccountStatus
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "displayName": self.display_name,
+            "status": self.status,
+        }
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> UserProfile:
+        return cls(
+            display_name=data["displayName"],
+            status=data["status"],
+        )
+
+
+class AccountStatus(StrEnum):
+    ACTIVE = "active"
+    SUSPENDED = "suspended"
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_escapes_reserved_words_and_invalid_names() {
+    let source_text = r#"
+    interface Route {
+      from: string;
+      lambda: string;
+      "content-type": string;
+      import(source: string, async: boolean): void;
+    }
+
+    enum Literal {
+      None = "none",
+      True = "true",
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"from __future__ import annotations
+
+from enum import StrEnum
+from typing import Protocol
+
+
+class Route(Protocol):
+    from_: str
+    lambda_: str
+    content_type: str
+    def import_(self, source: str, async_: bool) -> None: ...
+
+
+class Literal(StrEnum):
+    None_ = "none"
+    True_ = "true"
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_counts_up_implicit_enum_values_after_an_initializer() {
+    let source_text = r#"
+    enum Priority {
+      Low = 5,
+      Medium,
+      High,
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"from __future__ import annotations
+
+from enum import IntEnum
+
+
+class Priority(IntEnum):
+    Low = 5
+    Medium = 6
+    High = 7
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_keeps_integral_values_of_mixed_enums() {
+    let source_text = r#"
+    enum Answer {
+      No = 0,
+      Maybe = 2,
+      Yes = "yes",
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"from __future__ import annotations
+
+from enum import Enum
+
+
+class Answer(Enum):
+    No = 0
+    Maybe = 2
+    Yes = "yes"
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_type_aliases() {
+    let source_text = r#"
+    export type UserIds = string[];
+
+    type Settings = Record<string, boolean>;
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"from __future__ import annotations
+
+from typing import TypeAlias
+
+
+UserIds: TypeAlias = list[str]
+
+
+Settings: TypeAlias = dict[str, bool]
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+}
@@ -0,0 +1,75 @@
+use oxc_ast::ast::Program;
+
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::python_type_trait::PythonType;
+
+/// Names that need an import, grouped by module in the order they are emitted
+const PYTHON_IMPORTS: [(&str, &[&str]); 4] = [
+  ("dataclasses", &["dataclass"]),
+  ("enum", &["Enum", "IntEnum", "StrEnum", "auto"]),
+  (
+    "typing",
+    &[
+      "Any",
+      "Awaitable",
+      "Callable",
+      "Optional",
+      "Protocol",
+      "TypeAlias",
+    ],
+  ),
+  ("pydantic", &["BaseModel", "ConfigDict", "Field"]),
+];
+
+pub struct PythonTransformer;
+
+impl PythonTransformer {
+  pub fn transform(ast_program: &Program, ctx: &TransformContext) -> String {
+    let output = ast_program
+      .body
+      .iter()
+      .map(|statement| statement.to_python_type(ctx))
+      .collect::<Vec<_>>()
+      .join("\n\n");
+
+    // forward references between classes are resolved lazily with postponed annotations
+    let mut import_banners = String::from("from __future__ import annotations\n\n");
+    for (module_name, names) in PYTHON_IMPORTS {
+      let used_names = names
+        .iter()
+        .filter(|name| uses_python_name(&output, name))
+        .copied()
+        .collect::<Vec<_>>();
+
+      if !used_names.is_empty() {
+        import_banners.push_str(&format!(
+          "from {module_name} import {}\n",
+          used_names.join(", ")
+        ));
+      }
+    }
+
+    // `decimal.Decimal` -> `from decimal import Decimal`, `uuid` -> `import uuid`
+    for import_path in ctx.take_mapping_imports() {
+      match import_path.rsplit_once('.') {
+        Some((module_name, name)) => {
+          import_banners.push_str(&format!("from {module_name} import {name}\n"))
+        }
+        None => import_banners.push_str(&format!("import {import_path}\n")),
+      }
+    }
+
+    format!("{import_banners}\n\n{output}")
+  }
+}
+
+fn uses_python_name(code: &str, name: &str) -> bool {
+  let is_identifier_char = |x: char| x.is_alphanumeric() || x == '_';
+  code.match_indices(name).any(|(index, _)| {
+    let before = code[..index].chars().last();
+    let after = code[index + name.len()..].chars().next();
+    before.is_none_or(|x| !is_identifier_char(x) && x != '.')
+      && after.is_none_or(|x| !is_identifier_char(x))
+  })
+}
@@ -0,0 +1,356 @@
+use oxc_ast::ast::{
+  BindingPatternKind, Declaration, ExportNamedDeclaration, FormalParameters, PropertyKey,
+  Statement, TSEnumDeclaration, TSEnumMember, TSEnumMemberName, TSFunctionType,
+  TSInterfaceDeclaration, TSSignature, TSType, TSTypeAliasDeclaration, TSTypeReference,
+};
+
+use crate::{
+  config::transform_config::NamingConvention,
+  languages::{
+    python::{
+      python_class_type_trait::PythonClassType,
+      python_enum_display_type_trait::PythonEnumDisplayType,
+      python_fn_return_type_trait::PythonFunctionReturnType,
+      python_identifier::to_python_identifier, python_style,
+    },
+    shared::{
+      enum_values::get_enum_values_or_report, is_async_trait::IsAsyncType,
+      transform_context::TransformContext, type_mapping_trait::NativeTypeMapping,
+    },
+  },
+  utils::naming_utils::to_snake_case,
+};
+
+use super::python_is_protocol_type_trait::PythonIsProtocolType;
+
+pub trait PythonType {
+  fn to_python_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl PythonType for PropertyKey<'_> {
+  fn to_python_type(&self, _ctx: &TransformContext) -> String {
+    self
+      .static_name()
+      .map(|name| name.to_string())
+      .unwrap_or_else(|| "unknown-PropertyKey".to_owned())
+  }
+}
+
+impl PythonType for BindingPatternKind<'_> {
+  fn to_python_type(&self, _ctx: &TransformContext) -> String {
+    match self {
+      BindingPatternKind::BindingIdentifier(val) => to_python_identifier(&to_snake_case(&val.name)),
+      _ => "unknown-BindingPatternKind".to_owned(),
+    }
+  }
+}
+
+impl PythonType for TSTypeReference<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.type_name.to_string();
+    if let Some(type_mapping) = ctx.config.python.type_mappings.get(&type_name) {
+      let type_args = self
+        .type_parameters
+        .as_ref()
+        .map(|x| {
+          x.params
+            .iter()
+            .map(|x| x.to_python_type(ctx))
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      ctx.add_mapping_imports(&type_mapping.imports);
+      return type_mapping.to_native_type(&type_args, "[", "]");
+    }
+
+    let type_param = |index: usize| {
+      self
+        .type_parameters
+        .as_ref()
+        .and_then(|x| x.params.get(index))
+        .map(|x| x.to_python_type(ctx))
+        .unwrap_or_else(|| "Any".into())
+    };
+
+    match type_name.as_str() {
+      "Promise" => format!("Awaitable[{}]", type_param(0)),
+      "Array" | "ReadonlyArray" => format!("list[{}]", type_param(0)),
+      "Record" | "Map" | "ReadonlyMap" => format!("dict[{}, {}]", type_param(0), type_param(1)),
+      "Set" | "ReadonlySet" => format!("set[{}]", type_param(0)),
+      _ => ctx.declared_type_name(&type_name, "python"),
+    }
+  }
+}
+
+impl PythonType for TSFunctionType<'_> {
+  /// this is invoked from second level functions
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.return_type.type_annotation.to_python_type(ctx);
+    let param_types = self
+      .params
+      .items
+      .iter()
+      .map(|param| {
+        param
+          .pattern
+          .type_annotation
+          .as_ref()
+          .map(|t| t.type_annotation.to_python_type(ctx))
+          .unwrap_or_else(|| "Any".to_string())
+      })
+      .collect::<Vec<_>>()
+      .join(", ");
+
+    format!("Callable[[{param_types}], {type_name}]")
+  }
+}
+
+impl PythonType for TSType<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSType::TSStringKeyword(_) => "str".to_string(),
+      TSType::TSNumberKeyword(_) => "float".to_string(),
+      TSType::TSBooleanKeyword(_) => "bool".to_string(),
+      TSType::TSVoidKeyword(_) => "None".to_string(),
+      TSType::TSObjectKeyword(_) => "dict[str, Any]".to_string(),
+      TSType::TSTypeReference(ref_type) => ref_type.to_python_type(ctx),
+      TSType::TSFunctionType(fn_type) => fn_type.to_python_type(ctx),
+      TSType::TSArrayType(array_type) => {
+        let el_type = array_type.element_type.to_python_type(ctx);
+        format!("list[{el_type}]")
+      }
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_python_type(ctx),
+      _ => "Any".to_string(),
+    }
+  }
+}
+
+impl PythonType for FormalParameters<'_> {
+  /// Parameters of a protocol method, including `self`
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    let params = self.items.iter().map(|param| {
+      let type_annotation = param
+        .pattern
+        .type_annotation
+        .as_ref()
+        .map(|t| t.type_annotation.to_python_type(ctx))
+        .unwrap_or_else(|| "Any".to_string());
+
+      format!(
+        "{}: {}",
+        param.pattern.kind.to_python_type(ctx),
+        type_annotation
+      )
+    });
+
+    std::iter::once("self".to_string())
+      .chain(params)
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
+impl PythonType for TSSignature<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      TSSignature::TSPropertySignature(prop_sig) => {
+        let prop_name = to_python_member_name(
+          &prop_sig.key.to_python_type(ctx),
+          ctx.config.python.naming.properties,
+        );
+
+        // If property is a arrow function
+        if let Some(annotation) = prop_sig.type_annotation.as_ref()
+          && let TSType::TSFunctionType(fn_type) = &annotation.type_annotation
+        {
+          let fn_return_type = fn_type
+            .return_type
+            .type_annotation
+            .to_python_fn_return_type(ctx);
+          let fn_params = fn_type.params.to_python_type(ctx);
+          let async_val = if fn_type.return_type.type_annotation.is_async_type() {
+            "async "
+          } else {
+            ""
+          };
+
+          return format!(
+            "{}{}def {}({}) -> {}: ...",
+            python_style::INDENT_SPACE,
+            async_val,
+            prop_name,
+            fn_params,
+            fn_return_type
+          );
+        }
+
+        let type_annotation = prop_sig
+          .type_annotation
+          .as_ref()
+          .map(|annotation| annotation.type_annotation.to_python_type(ctx))
+          .unwrap_or_else(|| "Any".to_string());
+
+        let prop_type = if prop_sig.optional {
+          format!("Optional[{type_annotation}]")
+        } else {
+          type_annotation
+        };
+
+        if prop_sig.readonly {
+          format!(
+            "{0}@property\n{0}def {1}(self) -> {2}: ...",
+            python_style::INDENT_SPACE,
+            prop_name,
+            prop_type
+          )
+        } else {
+          format!("{}{}: {}", python_style::INDENT_SPACE, prop_name, prop_type)
+        }
+      }
+      TSSignature::TSMethodSignature(method_sig) => {
+        let params = method_sig.params.to_python_type(ctx);
+
+        let return_type = method_sig
+          .return_type
+          .as_ref()
+          .map(|r| r.type_annotation.to_python_fn_return_type(ctx))
+          .unwrap_or_else(|| "None".to_string());
+
+        let func_name = to_python_member_name(
+          &method_sig.key.to_python_type(ctx),
+          ctx.config.python.naming.methods,
+        );
+        let async_val = if method_sig.is_async_type() {
+          "async "
+        } else {
+          ""
+        };
+        format!(
+          "{}{}def {}({}) -> {}: ...",
+          python_style::INDENT_SPACE,
+          async_val,
+          func_name,
+          params,
+          return_type
+        )
+      }
+      _ => format!("{}# unknown-signature", python_style::INDENT_SPACE),
+    }
+  }
+}
+
+impl PythonType for TSInterfaceDeclaration<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    if self.is_python_protocol_type() {
+      let interface_name = ctx.declared_type_name(&self.id.name, "python");
+      let body_data = self
+        .body
+        .body
+        .iter()
+        .map(|signature| signature.to_python_type(ctx))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+      format!("class {interface_name}(Protocol):\n{body_data}\n")
+    } else {
+      self.to_python_class_type(ctx)
+    }
+  }
+}
+
+impl PythonType for Declaration<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      Declaration::TSInterfaceDeclaration(interface_decl) => interface_decl.to_python_type(ctx),
+      Declaration::TSEnumDeclaration(enum_decl) => enum_decl.to_python_type(ctx),
+      Declaration::TSTypeAliasDeclaration(alias_decl) => alias_decl.to_python_type(ctx),
+      _ => "# unknown-declaration\n".to_string(),
+    }
+  }
+}
+
+impl PythonType for TSTypeAliasDeclaration<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    format!(
+      "{}: TypeAlias = {}\n",
+      ctx.declared_type_name(&self.id.name, "python"),
+      self.type_annotation.to_python_type(ctx)
+    )
+  }
+}
+
+impl PythonType for ExportNamedDeclaration<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    self
+      .declaration
+      .as_ref()
+      .map(|d| d.to_python_type(ctx))
+      .unwrap_or_else(|| "# unknown-export-named-declaration\n".to_string())
+  }
+}
+
+impl PythonType for TSEnumMember<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    let name = match &self.id {
+      TSEnumMemberName::Identifier(enum_id) => enum_id.to_string(),
+      TSEnumMemberName::String(enum_string) => enum_string.to_string(),
+    };
+    match ctx.config.python.naming.enum_cases {
+      Some(convention) => to_python_identifier(&convention.apply(&name)),
+      None => to_python_identifier(&name),
+    }
+  }
+}
+
+impl PythonType for TSEnumDeclaration<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    let enum_name = ctx.declared_type_name(&self.id.name, "python");
+    let enum_values = get_enum_values_or_report(ctx, self, &enum_name);
+    let enum_type = if enum_values.is_some() {
+      self.to_python_enum_display_type()
+    } else {
+      "Enum".to_string()
+    };
+
+    let enum_cases = self
+      .members
+      .iter()
+      .enumerate()
+      .map(|(index, x)| {
+        let enum_value = match enum_values.as_ref().map(|values| &values[index]) {
+          Some(value) => value.to_literal(),
+          None => "auto()".to_string(),
+        };
+
+        format!(
+          "{}{} = {}",
+          python_style::INDENT_SPACE,
+          x.to_python_type(ctx),
+          enum_value
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    format!("class {enum_name}({enum_type}):\n{enum_cases}\n")
+  }
+}
+
+impl PythonType for Statement<'_> {
+  fn to_python_type(&self, ctx: &TransformContext) -> String {
+    match self {
+      Statement::ExportNamedDeclaration(export_decl) => export_decl.to_python_type(ctx),
+      Statement::TSInterfaceDeclaration(interface_decl) => interface_decl.to_python_type(ctx),
+      Statement::TSEnumDeclaration(enum_decl) => enum_decl.to_python_type(ctx),
+      Statement::TSTypeAliasDeclaration(alias_decl) => alias_decl.to_python_type(ctx),
+      _ => "# unknown-statement\n".to_string(),
+    }
+  }
+}
+
+/// A property or method name in the configured naming `convention`, `snake_case` by default
+pub fn to_python_member_name(name: &str, convention: Option<NamingConvention>) -> String {
+  to_python_identifier(
+    &convention.map_or_else(|| to_snake_case(name), |convention| convention.apply(name)),
+  )
+}
@@ -0,0 +1,46 @@
+use oxc_ast::ast::{PropertyKey, TSSignature, TSType, TSTypeAliasDeclaration};
+
+pub trait BrandedType {
+  /// The primitive behind a brand such as `string & { readonly __brand: "UserId" }`
+  fn get_branded_base_type(&self) -> Option<&TSType<'_>>;
+}
+
+impl BrandedType for TSTypeAliasDeclaration<'_> {
+  fn get_branded_base_type(&self) -> Option<&TSType<'_>> {
+    let TSType::TSIntersectionType(intersection_type) = &self.type_annotation else {
+      return None;
+    };
+
+    let is_primitive = |x: &TSType| {
+      matches!(
+        x,
+        TSType::TSStringKeyword(_)
+          | TSType::TSNumberKeyword(_)
+          | TSType::TSBooleanKeyword(_)
+          | TSType::TSBigIntKeyword(_)
+      )
+    };
+
+    // every member of the object part is a `__` marker, `__brand`, `__type` ..
+    let is_brand = |x: &TSType| match x {
+      TSType::TSTypeLiteral(type_literal) => {
+        !type_literal.members.is_empty()
+          && type_literal.members.iter().all(|member| match member {
+            TSSignature::TSPropertySignature(prop_sig) => matches!(
+              &prop_sig.key,
+              PropertyKey::StaticIdentifier(id_name) if id_name.name.starts_with("__")
+            ),
+            _ => false,
+          })
+      }
+      _ => false,
+    };
+
+    match intersection_type.types.as_slice() {
+      [base_type, brand] | [brand, base_type] if is_primitive(base_type) && is_brand(brand) => {
+        Some(base_type)
+      }
+      _ => None,
+    }
+  }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use oxc_ast::ast::{
+  BinaryOperator, Expression, TSEnumDeclaration, TSEnumMemberName, UnaryOperator,
+};
+
+use super::transform_context::TransformContext;
+
+/// Value of an enum member, computed the way TypeScript does
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnumValue {
+  Number(f64),
+  String(String),
+}
+
+impl EnumValue {
+  /// The value as a Swift or Kotlin literal, integers without a fraction
+  pub fn to_literal(&self) -> String {
+    match self {
+      EnumValue::Number(value) => value.to_string(),
+      EnumValue::String(value) => {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+      }
+    }
+  }
+}
+
+pub trait EnumValues {
+  /// Value of every member, `None` for a member that is not a constant expression
+  fn get_enum_values(&self) -> Vec<Option<EnumValue>>;
+
+  /// Whether at least one member declares its value
+  fn has_enum_initializer(&self) -> bool;
+}
+
+impl EnumValues for TSEnumDeclaration<'_> {
+  fn get_enum_values(&self) -> Vec<Option<EnumValue>> {
+    let enum_name = self.id.name.as_str();
+    let mut values = HashMap::<String, EnumValue>::new();
+    let mut previous = None;
+
+    self
+      .members
+      .iter()
+      .map(|member| {
+        let value = match &member.initializer {
+          Some(initializer) => evaluate(initializer, enum_name, &values),
+          // members without an initializer count up from the previous number
+          None => match &previous {
+            None => Some(EnumValue::Number(0.0)),
+            Some(Some(EnumValue::Number(value))) => Some(EnumValue::Number(value + 1.0)),
+            Some(_) => None,
+          },
+        };
+
+        let member_name = match &member.id {
+          TSEnumMemberName::Identifier(id_name) => id_name.name.to_string(),
+          TSEnumMemberName::String(literal) => literal.value.to_string(),
+        };
+        if let Some(value) = &value {
+          values.insert(member_name, value.clone());
+        }
+        previous = Some(value.clone());
+        value
+      })
+      .collect()
+  }
+
+  fn has_enum_initializer(&self) -> bool {
+    self.members.iter().any(|x| x.initializer.is_some())
+  }
+}
+
+/// Values of every member, reporting the enum when one of them is not constant
+pub fn get_enum_values_or_report(
+  ctx: &TransformContext,
+  enum_decl: &TSEnumDeclaration,
+  enum_name: &str,
+) -> Option<Vec<EnumValue>> {
+  let enum_values = enum_decl
+    .get_enum_values()
+    .into_iter()
+    .collect::<Option<Vec<_>>>();
+  if enum_values.is_none() {
+    ctx.report(format!(
+      "`{enum_name}` has members that are not constant, its cases are emitted without values"
+    ));
+  }
+  enum_values
+}
+
+/// Whether the values mix strings and numbers, `No = 0, Yes = "yes"`
+pub fn is_heterogeneous(values: &[EnumValue]) -> bool {
+  values.iter().any(|x| matches!(x, EnumValue::String(_)))
+    && values.iter().any(|x| matches!(x, EnumValue::Number(_)))
+}
+
+fn evaluate(
+  expression: &Expression,
+  enum_name: &str,
+  values: &HashMap<String, EnumValue>,
+) -> Option<EnumValue> {
+  match expression {
+    Expression::NumericLiteral(literal) => Some(EnumValue::Number(literal.value)),
+    Expression::StringLiteral(literal) => Some(EnumValue::String(literal.value.to_string())),
+    Expression::TemplateLiteral(template) if template.expressions.is_empty() => template
+      .quasis
+      .iter()
+      .map(|quasi| quasi.value.cooked.map(|cooked| cooked.to_string()))
+      .collect::<Option<String>>()
+      .map(EnumValue::String),
+    Expression::ParenthesizedExpression(paren) => evaluate(&paren.expression, enum_name, values),
+    // earlier members, `B = A` and `B = E.A`
+    Expression::Identifier(id_ref) => values.get(id_ref.name.as_str()).cloned(),
+    Expression::StaticMemberExpression(member_expr) => match &member_expr.object {
+      Expression::Identifier(object) if object.name == enum_name => {
+        values.get(member_expr.property.name.as_str()).cloned()
+      }
+      _ => None,
+    },
+    Expression::UnaryExpression(unary_expr) => {
+      let Some(EnumValue::Number(value)) = evaluate(&unary_expr.argument, enum_name, values) else {
+        return None;
+      };
+      match unary_expr.operator {
+        UnaryOperator::UnaryNegation => Some(-value),
+        UnaryOperator::UnaryPlus => Some(value),
+        UnaryOperator::BitwiseNot => Some(f64::from(!to_int32(value))),
+        _ => None,
+      }
+      .map(EnumValue::Number)
+    }
+    Expression::BinaryExpression(binary_expr) => {
+      let left = evaluate(&binary_expr.left, enum_name, values)?;
+      let right = evaluate(&binary_expr.right, enum_name, values)?;
+      evaluate_binary(binary_expr.operator, left, right)
+    }
+    _ => None,
+  }
+}
+
+fn evaluate_binary(
+  operator: BinaryOperator,
+  left: EnumValue,
+  right: EnumValue,
+) -> Option<EnumValue> {
+  let (left, right) = match (left, right) {
+    (EnumValue::Number(left), EnumValue::Number(right)) => (left, right),
+    // `+` with a string concatenates
+    (left, right) if operator == BinaryOperator::Addition => {
+      return Some(EnumValue::String(format!(
+        "{}{}",
+        to_display_string(&left),
+        to_display_string(&right)
+      )));
+    }
+    _ => return None,
+  };
+
+  let shift = to_int32(right) as u32 & 31;
+  let value = match operator {
+    BinaryOperator::Addition => left + right,
+    BinaryOperator::Subtraction => left - right,
+    BinaryOperator::Multiplication => left * right,
+    BinaryOperator::Division => left / right,
+    BinaryOperator::Remainder => left % right,
+    BinaryOperator::Exponential => left.powf(right),
+    BinaryOperator::ShiftLeft => f64::from(to_int32(left).wrapping_shl(shift)),
+    BinaryOperator::ShiftRight => f64::from(to_int32(left).wrapping_shr(shift)),
+    BinaryOperator::ShiftRightZeroFill => f64::from((to_int32(left) as u32).wrapping_shr(shift)),
+    BinaryOperator::BitwiseOR => f64::from(to_int32(left) | to_int32(right)),
+    BinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+    BinaryOperator::BitwiseXOR => f64::from(to_int32(left) ^ to_int32(right)),
+    _ => return None,
+  };
+  Some(EnumValue::Number(value))
+}
+
+fn to_display_string(value: &EnumValue) -> String {
+  match value {
+    EnumValue::Number(value) => value.to_string(),
+    EnumValue::String(value) => value.clone(),
+  }
+}
+
+/// The `ToInt32` conversion bitwise operators apply to their operands
+fn to_int32(value: f64) -> i32 {
+  if value.is_finite() {
+    value.trunc().rem_euclid(4_294_967_296.0) as u32 as i32
+  } else {
+    0
+  }
+}
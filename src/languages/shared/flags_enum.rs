@@ -0,0 +1,68 @@
+use oxc_ast::ast::{BinaryOperator, Expression, TSEnumDeclaration};
+
+use super::{
+  enum_values::{EnumValue, EnumValues},
+  transform_context::TransformContext,
+};
+
+/// Values of a bit flag enum, tagged `@flags` or made of powers of two and
+/// `|` combinations of them, `Read = 1 << 0, Write = 1 << 1, All = Read | Write`
+pub fn get_flag_values(ctx: &TransformContext, enum_decl: &TSEnumDeclaration) -> Option<Vec<i64>> {
+  let values = enum_decl
+    .get_enum_values()
+    .into_iter()
+    .map(|value| match value {
+      Some(EnumValue::Number(value)) if value.fract() == 0.0 && value >= 0.0 => Some(value as i64),
+      _ => None,
+    })
+    .collect::<Option<Vec<_>>>()?;
+
+  if ctx
+    .jsdoc(enum_decl.span)
+    .is_some_and(|jsdoc| jsdoc.tag("flags").is_some())
+  {
+    return Some(values);
+  }
+
+  if enum_decl.members.iter().any(|x| x.initializer.is_none()) {
+    return None;
+  }
+
+  let is_power_of_two = |value: i64| value > 0 && (value as u64).is_power_of_two();
+  let all_flags = values
+    .iter()
+    .filter(|value| is_power_of_two(**value))
+    .fold(0, |all_flags, value| all_flags | value);
+  let flag_count = values.iter().filter(|x| is_power_of_two(**x)).count();
+
+  // combinations have to be spelled out with `|`, `High = 3` is a plain number
+  let is_combination = |initializer: &Expression, value: i64| {
+    matches!(
+      initializer.get_inner_expression(),
+      Expression::BinaryExpression(binary_expr) if binary_expr.operator == BinaryOperator::BitwiseOR
+    ) && value & !all_flags == 0
+  };
+  let all_flag_values = enum_decl
+    .members
+    .iter()
+    .zip(&values)
+    .all(|(member, value)| {
+      *value == 0
+        || is_power_of_two(*value)
+        || member
+          .initializer
+          .as_ref()
+          .is_some_and(|initializer| is_combination(initializer, *value))
+    });
+
+  // `A = 1, B = 2` alone is as likely to be a plain numbering
+  let uses_bit_operators = enum_decl.members.iter().any(|member| {
+    matches!(
+      member.initializer.as_ref().map(|x| x.get_inner_expression()),
+      Some(Expression::BinaryExpression(binary_expr))
+        if matches!(binary_expr.operator, BinaryOperator::ShiftLeft | BinaryOperator::BitwiseOR)
+    )
+  });
+
+  (all_flag_values && flag_count >= 2 && (flag_count >= 3 || uses_bit_operators)).then_some(values)
+}
@@ -0,0 +1,48 @@
+/// A valid identifier for a TS key or member name, the characters an identifier
+/// cannot hold split words, `content-type` -> `contentType`, `1st` -> `_1st`
+pub fn to_valid_identifier(name: &str) -> String {
+  let mut identifier = String::new();
+  let mut is_word_start = false;
+  for char in name.chars() {
+    if char.is_alphanumeric() || char == '_' {
+      if is_word_start && !identifier.is_empty() {
+        identifier.extend(char.to_uppercase());
+      } else {
+        identifier.push(char);
+      }
+      is_word_start = false;
+    } else {
+      is_word_start = true;
+    }
+  }
+
+  match identifier.chars().next() {
+    None => "_".to_string(),
+    Some(first) if first.is_ascii_digit() => format!("_{identifier}"),
+    Some(_) => identifier,
+  }
+}
+
+/// `name` in backticks when it is one of `reserved_words`
+pub fn escape_reserved_word(name: &str, reserved_words: &[&str]) -> String {
+  if reserved_words.contains(&name) {
+    format!("`{name}`")
+  } else {
+    name.to_string()
+  }
+}
+
+/// `name` with a trailing underscore when it is one of `reserved_words`, for
+/// targets without an escape syntax, `class` -> `class_`
+pub fn suffix_reserved_word(name: &str, reserved_words: &[&str]) -> String {
+  if reserved_words.contains(&name) {
+    format!("{name}_")
+  } else {
+    name.to_string()
+  }
+}
+
+/// The name an escaped identifier declares, `` `default` `` -> `default`
+pub fn unescape_identifier(identifier: &str) -> &str {
+  identifier.trim_matches('`')
+}
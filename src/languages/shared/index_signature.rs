@@ -0,0 +1,27 @@
+use oxc_ast::ast::{TSIndexSignature, TSSignature, TSType};
+
+/// The `[key: K]: V` member of an interface or type literal, the first one wins
+pub fn get_index_signature<'a, 'b>(
+  members: &[&'b TSSignature<'a>],
+) -> Option<&'b TSIndexSignature<'a>> {
+  members.iter().find_map(|signature| match signature {
+    TSSignature::TSIndexSignature(index_sig) => Some(&**index_sig),
+    _ => None,
+  })
+}
+
+/// Whether the members are nothing but index signatures, `{ [key: string]: T }`
+pub fn is_dictionary_type(members: &[&TSSignature]) -> bool {
+  !members.is_empty()
+    && members
+      .iter()
+      .all(|signature| matches!(signature, TSSignature::TSIndexSignature(_)))
+}
+
+/// Type of the key of `[key: K]`
+pub fn get_index_key_type<'a, 'b>(index_sig: &'b TSIndexSignature<'a>) -> Option<&'b TSType<'a>> {
+  index_sig
+    .parameters
+    .first()
+    .map(|parameter| &parameter.type_annotation.type_annotation)
+}
@@ -0,0 +1,92 @@
+use oxc_ast::ast::{TSInterfaceDeclaration, TSIntersectionType, TSSignature, TSType};
+use oxc_span::GetSpan;
+
+use super::{member_name_trait::MemberName, transform_context::TransformContext};
+
+/// Same-file interfaces named by every part of `A & B`, `None` when a part is anything else
+pub fn get_intersected_interfaces<'a>(
+  intersection_type: &'a TSIntersectionType<'a>,
+  ctx: &TransformContext<'a>,
+) -> Option<Vec<&'a TSInterfaceDeclaration<'a>>> {
+  intersection_type
+    .types
+    .iter()
+    .map(|part| match part {
+      TSType::TSTypeReference(ref_type) if ref_type.type_parameters.is_none() => {
+        ctx.interface(&ref_type.type_name.to_string())
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+/// Members of the object literals and same-file interfaces of `A & { b: string }`, `None`
+/// when a part cannot be merged. A key declared again with a different type is reported
+/// and the first declaration wins
+pub fn get_merged_members<'a>(
+  intersection_type: &'a TSIntersectionType<'a>,
+  ctx: &TransformContext<'a>,
+  type_name: &str,
+) -> Option<Vec<&'a TSSignature<'a>>> {
+  let mut members: Vec<&'a TSSignature<'a>> = vec![];
+
+  for part_members in intersection_type
+    .types
+    .iter()
+    .map(|part| get_part_members(part, ctx, type_name))
+  {
+    for member in part_members? {
+      let member_name = member.to_member_name();
+      let existing_member = members
+        .iter()
+        .find(|x| !member_name.is_empty() && x.to_member_name() == member_name);
+
+      match existing_member {
+        Some(existing_member) => {
+          let existing_type = get_member_type_text(existing_member, ctx);
+          let member_type = get_member_type_text(member, ctx);
+          if existing_type != member_type {
+            ctx.report(format!(
+              "`{type_name}` declares `{member_name}` as both `{existing_type}` and `{member_type}`, keeping `{existing_type}`"
+            ));
+          }
+        }
+        None => members.push(member),
+      }
+    }
+  }
+
+  Some(members)
+}
+
+fn get_part_members<'a>(
+  part: &'a TSType<'a>,
+  ctx: &TransformContext<'a>,
+  type_name: &str,
+) -> Option<Vec<&'a TSSignature<'a>>> {
+  match part {
+    TSType::TSTypeLiteral(type_literal) => Some(type_literal.members.iter().collect()),
+    TSType::TSTypeReference(ref_type) if ref_type.type_parameters.is_none() => ctx
+      .interface(&ref_type.type_name.to_string())
+      .map(|interface_decl| interface_decl.body.body.iter().collect()),
+    TSType::TSParenthesizedType(parenthesized_type) => {
+      get_part_members(&parenthesized_type.type_annotation, ctx, type_name)
+    }
+    TSType::TSIntersectionType(intersection_type) => {
+      get_merged_members(intersection_type, ctx, type_name)
+    }
+    _ => None,
+  }
+}
+
+fn get_member_type_text(member: &TSSignature, ctx: &TransformContext) -> String {
+  match member {
+    TSSignature::TSPropertySignature(prop_sig) => prop_sig
+      .type_annotation
+      .as_ref()
+      .map(|annotation| ctx.source_text(annotation.type_annotation.span()))
+      .unwrap_or_default()
+      .to_string(),
+    _ => ctx.source_text(member.span()).to_string(),
+  }
+}
@@ -0,0 +1,93 @@
+/// A parsed `/** */` comment
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct JsDoc {
+  pub description: String,
+  pub tags: Vec<JsDocTag>,
+}
+
+/// A block tag such as `@param id - the user id`, `text` holds everything after the tag name
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsDocTag {
+  pub tag_name: String,
+  pub text: String,
+}
+
+impl JsDoc {
+  pub fn parse(comment_text: &str) -> Self {
+    let content = comment_text
+      .trim_start_matches("/**")
+      .trim_end_matches("*/");
+
+    let mut jsdoc = JsDoc::default();
+    let mut description_lines: Vec<&str> = vec![];
+
+    for line in content.lines() {
+      let line = line.trim_start();
+      let line = line.strip_prefix('*').unwrap_or(line);
+      let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+
+      if let Some(tag_line) = line.strip_prefix('@') {
+        let (tag_name, text) = tag_line
+          .split_once(char::is_whitespace)
+          .unwrap_or((tag_line, ""));
+        jsdoc.tags.push(JsDocTag {
+          tag_name: tag_name.to_string(),
+          text: text.trim().to_string(),
+        });
+      } else if let Some(tag) = jsdoc.tags.last_mut() {
+        if !tag.text.is_empty() || !line.is_empty() {
+          tag.text = format!("{}\n{}", tag.text, line);
+        }
+      } else {
+        description_lines.push(line);
+      }
+    }
+
+    jsdoc.description = description_lines.join("\n").trim().to_string();
+    for tag in &mut jsdoc.tags {
+      tag.text = tag.text.trim().to_string();
+    }
+    jsdoc
+  }
+
+  pub fn tag(&self, tag_name: &str) -> Option<&JsDocTag> {
+    self.tags.iter().find(|tag| tag.tag_name == tag_name)
+  }
+}
+
+impl JsDocTag {
+  /// The tag text on a single line, quotes and backslashes escaped for a string literal
+  pub fn to_string_literal_text(&self) -> String {
+    self
+      .text
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" ")
+      .replace('\\', "\\\\")
+      .replace('"', "\\\"")
+  }
+
+  /// `@param {string} [id] - the user id` -> `("id", "the user id")`
+  pub fn to_param_parts(&self) -> (String, String) {
+    let text = self.text.trim_start();
+    let text = if text.starts_with('{') {
+      text
+        .split_once('}')
+        .map(|(_, rest)| rest.trim_start())
+        .unwrap_or(text)
+    } else {
+      text
+    };
+
+    let (name, description) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let name = name
+      .trim_start_matches('[')
+      .split(['=', ']'])
+      .next()
+      .unwrap_or_default();
+    let description = description.trim_start();
+    let description = description.strip_prefix("- ").unwrap_or(description);
+
+    (name.to_string(), description.trim().to_string())
+  }
+}
@@ -0,0 +1,22 @@
+use oxc_ast::ast::{PropertyKey, TSSignature};
+
+pub trait MemberName {
+  /// The TS name of a member, empty for computed keys and index signatures
+  fn to_member_name(&self) -> String;
+}
+
+impl MemberName for TSSignature<'_> {
+  fn to_member_name(&self) -> String {
+    let key = match self {
+      TSSignature::TSPropertySignature(prop_sig) => &prop_sig.key,
+      TSSignature::TSMethodSignature(method_sig) => &method_sig.key,
+      _ => return "".to_string(),
+    };
+
+    match key {
+      PropertyKey::StaticIdentifier(id_name) => id_name.name.to_string(),
+      PropertyKey::StringLiteral(literal) => literal.value.to_string(),
+      _ => "".to_string(),
+    }
+  }
+}
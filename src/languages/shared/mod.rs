@@ -1,2 +1,15 @@
-pub mod enum_trait;
+pub mod branded_type_trait;
+pub mod enum_values;
+pub mod flags_enum;
+pub mod identifier;
+pub mod index_signature;
+pub mod intersection_members;
 pub mod is_async_trait;
+pub mod jsdoc;
+pub mod member_name_trait;
+pub mod native_overrides;
+pub mod number_kind;
+pub mod stream_types;
+pub mod transform_context;
+pub mod tuple_elements;
+pub mod type_mapping_trait;
@@ -0,0 +1,45 @@
+use super::jsdoc::JsDoc;
+
+/// Per declaration or member overrides read from JSDoc tags,
+///
+/// - `@native-type Int` or `@swift-type Int` replaces the type
+/// - `@swift-name`, `@kotlin-name` renames the declaration or member
+/// - `@native-ignore` skips it
+/// - `@native-conformance Hashable, Sendable` adds conformances
+/// - `@swift-record-style observable` picks the shape of a record
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NativeOverrides {
+  pub native_type: Option<String>,
+  pub name: Option<String>,
+  pub is_ignored: bool,
+  pub conformances: Vec<String>,
+  pub record_style: Option<String>,
+}
+
+impl NativeOverrides {
+  pub fn from_jsdoc(jsdoc: &JsDoc, target: &str) -> Self {
+    let tag_value = |tag_name: &str| {
+      jsdoc
+        .tag(tag_name)
+        .map(|tag| tag.text.trim().to_string())
+        .filter(|text| !text.is_empty())
+    };
+
+    let conformances = jsdoc
+      .tags
+      .iter()
+      .filter(|tag| tag.tag_name == "native-conformance")
+      .flat_map(|tag| tag.text.split([',', ' ', '\n']))
+      .filter(|conformance| !conformance.is_empty())
+      .map(|conformance| conformance.to_string())
+      .collect();
+
+    Self {
+      native_type: tag_value(&format!("{target}-type")).or_else(|| tag_value("native-type")),
+      name: tag_value(&format!("{target}-name")),
+      is_ignored: jsdoc.tag("native-ignore").is_some(),
+      conformances,
+      record_style: tag_value(&format!("{target}-record-style")),
+    }
+  }
+}
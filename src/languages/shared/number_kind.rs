@@ -0,0 +1,42 @@
+/// Native shape of a TS `number`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NumberKind {
+  #[default]
+  Double,
+  Int,
+  Long,
+}
+
+impl NumberKind {
+  /// Kind implied by an alias of `number`, `type Int = number` or `type Long = number`
+  pub fn from_alias_name(alias_name: &str) -> Option<Self> {
+    match alias_name.to_lowercase().as_str() {
+      "int" | "integer" | "int32" => Some(NumberKind::Int),
+      "long" | "int64" => Some(NumberKind::Long),
+      _ => None,
+    }
+  }
+}
+
+/// Glob match where `*` matches any run of characters, `*Id` matches `userId`
+pub fn matches_name_pattern(name: &str, pattern: &str) -> bool {
+  let mut parts = pattern.split('*');
+  let first_part = parts.next().unwrap_or_default();
+  let Some(mut rest) = name.strip_prefix(first_part) else {
+    return false;
+  };
+
+  let parts = parts.collect::<Vec<_>>();
+  match parts.split_last() {
+    None => rest.is_empty(),
+    Some((last_part, middle_parts)) => {
+      for part in middle_parts {
+        match rest.find(part) {
+          Some(index) => rest = &rest[index + part.len()..],
+          None => return false,
+        }
+      }
+      rest.ends_with(last_part)
+    }
+  }
+}
@@ -0,0 +1,77 @@
+use oxc_ast::ast::{TSMethodSignature, TSType};
+
+/// Types of values delivered over time, emitted as async streams
+pub const STREAM_TYPE_NAMES: [&str; 4] = [
+  "AsyncIterable",
+  "AsyncIterableIterator",
+  "AsyncGenerator",
+  "Observable",
+];
+
+/// A subscription method such as `onMessage(listener: (e: Message) => void): () => void`
+pub struct ListenerSubscription<'a> {
+  /// The method name without `on`, `message`
+  pub event_name: String,
+  /// Type of the listener param, `None` for listeners without one
+  pub event_type: Option<&'a TSType<'a>>,
+}
+
+/// The subscription a method stands for, a `onX` method taking a single
+/// listener and returning nothing or an unsubscribe function
+pub fn get_listener_subscription<'a>(
+  method_sig: &'a TSMethodSignature<'a>,
+) -> Option<ListenerSubscription<'a>> {
+  let method_name = method_sig.key.static_name()?;
+  let event_name = method_name.strip_prefix("on")?;
+  if !event_name.starts_with(|x: char| x.is_ascii_uppercase()) {
+    return None;
+  }
+
+  let returns_unsubscribe = match method_sig.return_type.as_ref().map(|r| &r.type_annotation) {
+    None | Some(TSType::TSVoidKeyword(_)) => true,
+    Some(TSType::TSFunctionType(fn_type)) => {
+      fn_type.params.items.is_empty()
+        && matches!(
+          fn_type.return_type.type_annotation,
+          TSType::TSVoidKeyword(_)
+        )
+    }
+    _ => false,
+  };
+  if !returns_unsubscribe || method_sig.params.items.len() != 1 {
+    return None;
+  }
+
+  let listener_type = method_sig.params.items[0]
+    .pattern
+    .type_annotation
+    .as_ref()?;
+  let TSType::TSFunctionType(listener_type) = &listener_type.type_annotation else {
+    return None;
+  };
+  if listener_type.params.items.len() > 1
+    || !matches!(
+      listener_type.return_type.type_annotation,
+      TSType::TSVoidKeyword(_)
+    )
+  {
+    return None;
+  }
+
+  let mut event_chars = event_name.chars();
+  let event_name = event_chars
+    .next()
+    .map(|first| format!("{}{}", first.to_ascii_lowercase(), event_chars.as_str()))
+    .unwrap_or_default();
+  let event_type = listener_type
+    .params
+    .items
+    .first()
+    .and_then(|param| param.pattern.type_annotation.as_ref())
+    .map(|annotation| &annotation.type_annotation);
+
+  Some(ListenerSubscription {
+    event_name,
+    event_type,
+  })
+}
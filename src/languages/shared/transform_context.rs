@@ -0,0 +1,379 @@
+use std::{
+  cell::{Cell, RefCell},
+  collections::{BTreeSet, HashMap, HashSet},
+};
+
+use oxc_ast::{
+  Comment,
+  ast::{
+    Declaration, Program, Statement, TSInterfaceDeclaration, TSSignature, TSType,
+    TSTypeParameterDeclaration,
+  },
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::config::transform_config::TransformConfig;
+
+use super::{
+  jsdoc::JsDoc,
+  native_overrides::NativeOverrides,
+  number_kind::{NumberKind, matches_name_pattern},
+};
+
+/// State shared by the emitters while transforming a program
+pub struct TransformContext<'a> {
+  pub config: &'a TransformConfig,
+  source_text: &'a str,
+  comments: &'a [Comment],
+  /// Start of an exported declaration -> start of its `export` statement
+  export_starts: HashMap<u32, u32>,
+  /// Interface, enum and type alias names -> span of their declaration
+  declaration_spans: HashMap<String, Span>,
+  /// Names of the generic params of the declarations and their methods
+  type_parameters: HashSet<String>,
+  /// Aliases of `number` that stand for an integer, `type Int = number`
+  integer_aliases: HashMap<String, NumberKind>,
+  /// How a `number` is emitted by the member currently being transformed
+  number_kind: Cell<NumberKind>,
+  interfaces: HashMap<String, &'a TSInterfaceDeclaration<'a>>,
+  /// Problems found while transforming, such as conflicting members
+  diagnostics: RefCell<Vec<String>>,
+  /// Name for a declaration generated from an inline type, such as `OrderRange`
+  /// for the tuple of `Order.range`
+  name_hint: RefCell<Option<String>>,
+  /// `(name, code)` of declarations generated while transforming, emitted after the program
+  generated_declarations: RefCell<Vec<(String, String)>>,
+  /// Built-in types the emitted code refers to, imported by the transformer
+  imported_types: RefCell<BTreeSet<String>>,
+  /// Imports of the configured type mappings the emitted code uses
+  mapping_imports: RefCell<BTreeSet<String>>,
+}
+
+impl<'a> TransformContext<'a> {
+  pub fn new(ast_program: &'a Program<'a>, config: &'a TransformConfig) -> Self {
+    let export_starts = ast_program
+      .body
+      .iter()
+      .filter_map(|statement| match statement {
+        Statement::ExportNamedDeclaration(export_decl) => export_decl
+          .declaration
+          .as_ref()
+          .map(|d| (d.span().start, export_decl.span.start)),
+        _ => None,
+      })
+      .collect();
+
+    let declarations = ast_program
+      .body
+      .iter()
+      .filter_map(|statement| match statement {
+        Statement::ExportNamedDeclaration(export_decl) => export_decl.declaration.as_ref(),
+        _ => statement.as_declaration(),
+      })
+      .collect::<Vec<_>>();
+
+    let declaration_spans = declarations
+      .iter()
+      .filter_map(|declaration| match declaration {
+        Declaration::TSInterfaceDeclaration(interface_decl) => {
+          Some((interface_decl.id.name.to_string(), interface_decl.span))
+        }
+        Declaration::TSEnumDeclaration(enum_decl) => {
+          Some((enum_decl.id.name.to_string(), enum_decl.span))
+        }
+        Declaration::TSTypeAliasDeclaration(alias_decl) => {
+          Some((alias_decl.id.name.to_string(), alias_decl.span))
+        }
+        _ => None,
+      })
+      .collect();
+
+    let type_parameter_names = |params: Option<&TSTypeParameterDeclaration>| {
+      params
+        .iter()
+        .flat_map(|params| params.params.iter())
+        .map(|param| param.name.name.to_string())
+        .collect::<Vec<_>>()
+    };
+    let type_parameters = declarations
+      .iter()
+      .flat_map(|declaration| match declaration {
+        Declaration::TSInterfaceDeclaration(interface_decl) => {
+          let method_params =
+            interface_decl
+              .body
+              .body
+              .iter()
+              .flat_map(|signature| match signature {
+                TSSignature::TSMethodSignature(method_sig) => {
+                  type_parameter_names(method_sig.type_parameters.as_deref())
+                }
+                _ => vec![],
+              });
+          type_parameter_names(interface_decl.type_parameters.as_deref())
+            .into_iter()
+            .chain(method_params)
+            .collect()
+        }
+        Declaration::TSTypeAliasDeclaration(alias_decl) => {
+          type_parameter_names(alias_decl.type_parameters.as_deref())
+        }
+        _ => vec![],
+      })
+      .collect();
+
+    let interfaces = declarations
+      .iter()
+      .filter_map(|declaration| match declaration {
+        Declaration::TSInterfaceDeclaration(interface_decl) => {
+          Some((interface_decl.id.name.to_string(), &**interface_decl))
+        }
+        _ => None,
+      })
+      .collect();
+
+    let mut ctx = Self {
+      config,
+      source_text: ast_program.source_text,
+      comments: &ast_program.comments,
+      export_starts,
+      declaration_spans,
+      type_parameters,
+      integer_aliases: HashMap::new(),
+      number_kind: Cell::new(NumberKind::Double),
+      interfaces,
+      diagnostics: RefCell::new(vec![]),
+      name_hint: RefCell::new(None),
+      generated_declarations: RefCell::new(vec![]),
+      imported_types: RefCell::new(BTreeSet::new()),
+      mapping_imports: RefCell::new(BTreeSet::new()),
+    };
+
+    // `/** @int */ type Count = number` counts as well as the well-known names
+    let integer_aliases = declarations
+      .iter()
+      .filter_map(|declaration| match declaration {
+        Declaration::TSTypeAliasDeclaration(alias_decl)
+          if matches!(alias_decl.type_annotation, TSType::TSNumberKeyword(_)) =>
+        {
+          let alias_name = alias_decl.id.name.to_string();
+          NumberKind::from_alias_name(&alias_name)
+            .or_else(|| ctx.jsdoc_number_kind(alias_decl.span))
+            .map(|kind| (alias_name, kind))
+        }
+        _ => None,
+      })
+      .collect();
+    ctx.integer_aliases = integer_aliases;
+    ctx
+  }
+
+  /// Interface declared in the same file
+  pub fn interface(&self, interface_name: &str) -> Option<&'a TSInterfaceDeclaration<'a>> {
+    self.interfaces.get(interface_name).copied()
+  }
+
+  /// Whether an interface, enum or type alias named `type_name` is declared in the same file
+  pub fn is_declared(&self, type_name: &str) -> bool {
+    self.declaration_spans.contains_key(type_name)
+  }
+
+  /// Whether `type_name` is a generic param of a declaration or method, such as `T`
+  pub fn is_type_parameter(&self, type_name: &str) -> bool {
+    self.type_parameters.contains(type_name)
+  }
+
+  pub fn source_text(&self, span: Span) -> &'a str {
+    &self.source_text[span.start as usize..span.end as usize]
+  }
+
+  pub fn report(&self, diagnostic: String) {
+    self.diagnostics.borrow_mut().push(diagnostic);
+  }
+
+  pub fn take_diagnostics(&self) -> Vec<String> {
+    self.diagnostics.take()
+  }
+
+  pub fn name_hint(&self) -> Option<String> {
+    self.name_hint.borrow().clone()
+  }
+
+  /// Runs `transform` with `name_hint` as the name of generated declarations
+  pub fn with_name_hint<T>(&self, name_hint: String, transform: impl FnOnce() -> T) -> T {
+    let outer_name_hint = self.name_hint.replace(Some(name_hint));
+    let output = transform();
+    self.name_hint.replace(outer_name_hint);
+    output
+  }
+
+  /// Adds a declaration named after `name`, a different declaration already using
+  /// the name gets a number suffix, returns the name in use
+  pub fn add_generated_declaration(&self, name: &str, to_code: impl Fn(&str) -> String) -> String {
+    let mut generated_declarations = self.generated_declarations.borrow_mut();
+    let mut suffix = 1;
+    loop {
+      let declaration_name = if suffix == 1 {
+        name.to_string()
+      } else {
+        format!("{name}{suffix}")
+      };
+      let code = to_code(&declaration_name);
+
+      match generated_declarations
+        .iter()
+        .find(|(existing_name, _)| *existing_name == declaration_name)
+      {
+        Some((_, existing_code)) if *existing_code == code => return declaration_name,
+        Some(_) => suffix += 1,
+        None => {
+          generated_declarations.push((declaration_name.clone(), code));
+          return declaration_name;
+        }
+      }
+    }
+  }
+
+  /// Removes the generated declaration `name` so it can be emitted in place
+  pub fn take_generated_declaration(&self, name: &str) -> Option<String> {
+    let mut generated_declarations = self.generated_declarations.borrow_mut();
+    let index = generated_declarations
+      .iter()
+      .position(|(existing_name, _)| existing_name == name)?;
+    Some(generated_declarations.remove(index).1)
+  }
+
+  pub fn take_generated_declarations(&self) -> Vec<(String, String)> {
+    self.generated_declarations.take()
+  }
+
+  /// How the emitted code refers to the built-in `type_name`, recording that it
+  /// needs an import. A declaration of the same name in the file takes the plain
+  /// name, the type is then referred to by `qualified_name`
+  pub fn import_type(&self, type_name: &str, qualified_name: &str) -> String {
+    self
+      .imported_types
+      .borrow_mut()
+      .insert(type_name.to_string());
+    if self.is_declared(type_name) {
+      qualified_name.to_string()
+    } else {
+      type_name.to_string()
+    }
+  }
+
+  /// Built-in types referred to since the last call
+  pub fn take_imported_types(&self) -> BTreeSet<String> {
+    self.imported_types.take()
+  }
+
+  /// Records the imports of a configured type mapping the emitted code uses
+  pub fn add_mapping_imports(&self, imports: &[String]) {
+    self
+      .mapping_imports
+      .borrow_mut()
+      .extend(imports.iter().cloned());
+  }
+
+  /// Imports of the type mappings used since the last call, sorted
+  pub fn take_mapping_imports(&self) -> Vec<String> {
+    self.mapping_imports.take().into_iter().collect()
+  }
+
+  pub fn is_exported(&self, span: Span) -> bool {
+    self.export_starts.contains_key(&span.start)
+  }
+
+  /// The `/** */` block attached to a node, for exported declarations the
+  /// comment is attached to the `export` keyword instead
+  pub fn jsdoc(&self, span: Span) -> Option<JsDoc> {
+    let attached_to = self
+      .export_starts
+      .get(&span.start)
+      .copied()
+      .unwrap_or(span.start);
+
+    self
+      .comments
+      .iter()
+      .rev()
+      .find(|comment| comment.attached_to == attached_to && comment.is_block())
+      .map(|comment| self.source_text(comment.span))
+      .filter(|comment_text| comment_text.starts_with("/**"))
+      .map(JsDoc::parse)
+  }
+
+  /// Overrides for a declaration or member, `target` is the prefix of the
+  /// target specific tags such as `swift` for `@swift-name`
+  pub fn native_overrides(&self, span: Span, target: &str) -> NativeOverrides {
+    self
+      .jsdoc(span)
+      .map(|jsdoc| NativeOverrides::from_jsdoc(&jsdoc, target))
+      .unwrap_or_default()
+  }
+
+  /// The name a type reference should use, honouring the overrides of the
+  /// declaration it points to
+  pub fn native_type_name(&self, type_name: &str, target: &str) -> String {
+    self
+      .declaration_spans
+      .get(type_name)
+      .map(|span| self.native_overrides(*span, target))
+      .and_then(|overrides| overrides.native_type.or(overrides.name))
+      .unwrap_or_else(|| self.declared_type_name(type_name, target))
+  }
+
+  /// A type declared in the program in the `types` naming convention of `target`
+  pub fn declared_type_name(&self, type_name: &str, target: &str) -> String {
+    match self.config.naming(target).types {
+      Some(convention) if self.is_declared(type_name) => convention.apply(type_name),
+      _ => type_name.to_string(),
+    }
+  }
+
+  fn jsdoc_number_kind(&self, span: Span) -> Option<NumberKind> {
+    self
+      .jsdoc(span)
+      .filter(|jsdoc| jsdoc.tag("int").is_some() || jsdoc.tag("integer").is_some())
+      .map(|_| NumberKind::Int)
+  }
+
+  /// Kind of the numbers in a member or param, from its `@int` / `@integer`
+  /// tag or the configured name patterns
+  pub fn member_number_kind(&self, span: Span, name: &str) -> NumberKind {
+    self.jsdoc_number_kind(span).unwrap_or_else(|| {
+      if self
+        .config
+        .integer_name_patterns
+        .iter()
+        .any(|pattern| matches_name_pattern(name, pattern))
+      {
+        NumberKind::Int
+      } else {
+        NumberKind::Double
+      }
+    })
+  }
+
+  /// Kind of the numbers in a method from its `@int` / `@integer` tag, the name
+  /// patterns only describe properties and params
+  pub fn method_number_kind(&self, span: Span) -> NumberKind {
+    self.jsdoc_number_kind(span).unwrap_or(NumberKind::Double)
+  }
+
+  pub fn integer_alias(&self, type_name: &str) -> Option<NumberKind> {
+    self.integer_aliases.get(type_name).copied()
+  }
+
+  pub fn number_kind(&self) -> NumberKind {
+    self.number_kind.get()
+  }
+
+  /// Runs `transform` with every `number` inside it emitted as `number_kind`
+  pub fn with_number_kind<T>(&self, number_kind: NumberKind, transform: impl FnOnce() -> T) -> T {
+    let outer_number_kind = self.number_kind.replace(number_kind);
+    let output = transform();
+    self.number_kind.set(outer_number_kind);
+    output
+  }
+}
@@ -0,0 +1,47 @@
+use oxc_ast::ast::{TSTupleElement, TSTupleType, TSType};
+
+/// A tuple element with the label, `?` and `...` markers unwrapped
+pub struct TupleElement<'a> {
+  pub label: Option<String>,
+  pub element_type: &'a TSType<'a>,
+  pub optional: bool,
+  pub rest: bool,
+}
+
+pub fn get_tuple_elements<'a>(tuple_type: &'a TSTupleType<'a>) -> Vec<TupleElement<'a>> {
+  tuple_type
+    .element_types
+    .iter()
+    .filter_map(to_tuple_element)
+    .collect()
+}
+
+fn to_tuple_element<'a>(element: &'a TSTupleElement<'a>) -> Option<TupleElement<'a>> {
+  match element {
+    TSTupleElement::TSOptionalType(optional_type) => Some(TupleElement {
+      label: None,
+      element_type: &optional_type.type_annotation,
+      optional: true,
+      rest: false,
+    }),
+    TSTupleElement::TSRestType(rest_type) => Some(TupleElement {
+      label: None,
+      element_type: &rest_type.type_annotation,
+      optional: false,
+      rest: true,
+    }),
+    TSTupleElement::TSNamedTupleMember(named_member) => {
+      to_tuple_element(&named_member.element_type).map(|element| TupleElement {
+        label: Some(named_member.label.name.to_string()),
+        optional: element.optional || named_member.optional,
+        ..element
+      })
+    }
+    _ => element.as_ts_type().map(|element_type| TupleElement {
+      label: None,
+      element_type,
+      optional: false,
+      rest: false,
+    }),
+  }
+}
@@ -0,0 +1,37 @@
+use crate::config::transform_config::TypeMapping;
+
+pub trait NativeTypeMapping {
+  /// The native type with the generic arguments forwarded, `open` and `close`
+  /// are the brackets of the target such as `<` and `>`
+  fn to_native_type(&self, type_args: &[String], open: &str, close: &str) -> String;
+}
+
+impl NativeTypeMapping for TypeMapping {
+  fn to_native_type(&self, type_args: &[String], open: &str, close: &str) -> String {
+    if self.native_type.contains('$') {
+      // replace from the last index so `$1` does not clobber `$10`
+      return type_args.iter().enumerate().rev().fold(
+        self.native_type.clone(),
+        |native_type, (index, type_arg)| native_type.replace(&format!("${index}"), type_arg),
+      );
+    }
+
+    if type_args.is_empty() {
+      self.native_type.clone()
+    } else {
+      format!("{}{open}{}{close}", self.native_type, type_args.join(", "))
+    }
+  }
+}
+
+/// Checks for `type_name` as a whole word, `Foo.Date` and `DateTime` do not count as `Date`
+pub fn uses_type_name(code: &str, type_name: &str) -> bool {
+  let is_identifier_char = |x: char| x.is_alphanumeric() || x == '_';
+  !type_name.is_empty()
+    && code.match_indices(type_name).any(|(index, _)| {
+      let before = code[..index].chars().last();
+      let after = code[index + type_name.len()..].chars().next();
+      before.is_none_or(|x| !is_identifier_char(x) && x != '.')
+        && after.is_none_or(|x| !is_identifier_char(x))
+    })
+}
@@ -1,5 +1,11 @@
+pub mod swift_access_level;
+pub mod swift_additional_properties;
+pub mod swift_doc_comment_trait;
+pub mod swift_enum_coding;
 pub mod swift_enum_display_type_trait;
 pub mod swift_fn_return_type_trait;
+pub mod swift_identifier;
+pub mod swift_imports;
 pub mod swift_is_protocol_type_trait;
 pub mod swift_struct_type_trait;
 pub mod swift_style;
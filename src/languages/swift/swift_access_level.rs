@@ -0,0 +1,26 @@
+use oxc_span::Span;
+
+use crate::{
+  config::transform_config::SwiftAccessLevel,
+  languages::shared::transform_context::TransformContext,
+};
+
+/// Modifier of a declaration and its members, exported declarations take the
+/// configured access level and the others stay internal
+pub fn get_swift_access_level(ctx: &TransformContext, span: Span) -> &'static str {
+  if !ctx.is_exported(span) {
+    return "";
+  }
+
+  // `open` only applies to classes
+  match ctx.config.swift.access_level {
+    SwiftAccessLevel::Public | SwiftAccessLevel::Open => "public ",
+    SwiftAccessLevel::Package => "package ",
+    SwiftAccessLevel::Internal => "",
+  }
+}
+
+/// Exported classes are `open` instead of `final` with the `open` access level
+pub fn is_swift_open_class(ctx: &TransformContext, span: Span) -> bool {
+  ctx.is_exported(span) && ctx.config.swift.access_level == SwiftAccessLevel::Open
+}
@@ -0,0 +1,72 @@
+use crate::languages::swift::{
+  swift_struct_type_trait::{SwiftProperty, get_swift_coding_keys},
+  swift_style,
+};
+
+/// `Codable` members of a struct that collects the keys it does not declare
+/// into `additionalProperties`
+pub fn get_swift_additional_properties_coding(
+  properties: &[SwiftProperty],
+  value_type: &str,
+  access_level: &str,
+  is_required_init: bool,
+) -> String {
+  // classes that can be subclassed need a `required` decoding init
+  let required = if is_required_init { "required " } else { "" };
+  let indent = swift_style::INDENT_SPACE;
+  let indent2 = indent.repeat(2);
+  let indent3 = indent.repeat(3);
+
+  let decode_properties = properties
+    .iter()
+    .map(|property| {
+      let if_present = if property.optional { "IfPresent" } else { "" };
+      format!(
+        "{indent2}{0} = try container.decode{if_present}({1}.self, forKey: .{0})\n",
+        property.name, property.type_annotation
+      )
+    })
+    .collect::<String>();
+
+  let encode_properties = properties
+    .iter()
+    .map(|property| {
+      let if_present = if property.optional { "IfPresent" } else { "" };
+      format!(
+        "{indent2}try container.encode{if_present}({0}, forKey: .{0})\n",
+        property.name
+      )
+    })
+    .collect::<String>();
+
+  format!(
+    "\n\
+{}
+
+{indent}private struct AdditionalPropertyKey: CodingKey {{
+{indent2}let stringValue: String
+{indent2}var intValue: Int? {{ nil }}
+{indent2}init(stringValue: String) {{ self.stringValue = stringValue }}
+{indent2}init?(intValue: Int) {{ nil }}
+{indent}}}
+
+{indent}{access_level}{required}init(from decoder: Decoder) throws {{
+{indent2}let container = try decoder.container(keyedBy: CodingKeys.self)
+{decode_properties}{indent2}let additionalContainer = try decoder.container(keyedBy: AdditionalPropertyKey.self)
+{indent2}var additionalProperties: [String: {value_type}] = [:]
+{indent2}for key in additionalContainer.allKeys where CodingKeys(stringValue: key.stringValue) == nil {{
+{indent3}additionalProperties[key.stringValue] = try additionalContainer.decode({value_type}.self, forKey: key)
+{indent2}}}
+{indent2}self.additionalProperties = additionalProperties
+{indent}}}
+
+{indent}{access_level}func encode(to encoder: Encoder) throws {{
+{indent2}var container = encoder.container(keyedBy: CodingKeys.self)
+{encode_properties}{indent2}var additionalContainer = encoder.container(keyedBy: AdditionalPropertyKey.self)
+{indent2}for (key, value) in additionalProperties {{
+{indent3}try additionalContainer.encode(value, forKey: AdditionalPropertyKey(stringValue: key))
+{indent2}}}
+{indent}}}",
+    get_swift_coding_keys(properties, "private ")
+  )
+}
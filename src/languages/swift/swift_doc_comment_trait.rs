@@ -0,0 +1,89 @@
+use oxc_span::Span;
+
+use crate::languages::shared::{jsdoc::JsDoc, transform_context::TransformContext};
+
+pub trait SwiftDocComment {
+  /// `///` markup lines, each one ending with a new line
+  fn to_swift_doc_comment(&self, indent: &str) -> String;
+
+  /// Attribute lines such as `@available(*, deprecated)`, each one ending with a new line
+  fn to_swift_attributes(&self, indent: &str) -> String;
+}
+
+impl SwiftDocComment for JsDoc {
+  fn to_swift_doc_comment(&self, indent: &str) -> String {
+    let mut lines = self
+      .description
+      .lines()
+      .map(|x| x.to_string())
+      .collect::<Vec<_>>();
+
+    let mut tag_lines: Vec<String> = vec![];
+    for tag in &self.tags {
+      let callout = match tag.tag_name.as_str() {
+        "param" => {
+          let (param_name, description) = tag.to_param_parts();
+          format!("- Parameter {param_name}: {description}")
+        }
+        "returns" | "return" => format!("- Returns: {}", tag.text),
+        "throws" | "exception" => format!("- Throws: {}", tag.text),
+        "see" => format!("- SeeAlso: {}", tag.text),
+        "example" => {
+          tag_lines.push("```".to_string());
+          tag_lines.extend(tag.text.lines().map(|x| x.to_string()));
+          tag_lines.push("```".to_string());
+          continue;
+        }
+        _ => continue,
+      };
+
+      // continuation lines are indented to stay part of the callout
+      let mut callout_lines = callout.trim_end().lines();
+      if let Some(first_line) = callout_lines.next() {
+        tag_lines.push(first_line.to_string());
+      }
+      tag_lines.extend(callout_lines.map(|x| format!("  {x}")));
+    }
+
+    if !lines.is_empty() && !tag_lines.is_empty() {
+      lines.push("".to_string());
+    }
+    lines.extend(tag_lines);
+
+    lines
+      .iter()
+      .map(|x| {
+        if x.is_empty() {
+          format!("{indent}///\n")
+        } else {
+          format!("{indent}/// {x}\n")
+        }
+      })
+      .collect()
+  }
+
+  fn to_swift_attributes(&self, indent: &str) -> String {
+    match self.tag("deprecated") {
+      Some(tag) if !tag.text.is_empty() => format!(
+        "{indent}@available(*, deprecated, message: \"{}\")\n",
+        tag.to_string_literal_text()
+      ),
+      Some(_) => format!("{indent}@available(*, deprecated)\n"),
+      None => "".to_string(),
+    }
+  }
+}
+
+/// Doc comment and attributes of the node at `span`, empty when it has no JSDoc
+pub fn get_swift_doc_comment(ctx: &TransformContext, span: Span, indent: &str) -> String {
+  ctx
+    .jsdoc(span)
+    .map(|jsdoc| {
+      format!(
+        "{}{}",
+        jsdoc.to_swift_doc_comment(indent),
+        jsdoc.to_swift_attributes(indent)
+      )
+    })
+    .unwrap_or_default()
+}
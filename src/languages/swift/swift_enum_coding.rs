@@ -0,0 +1,192 @@
+use crate::languages::{shared::enum_values::EnumValue, swift::swift_style};
+
+/// A case of an enum with its raw value
+pub struct SwiftEnumCase {
+  pub doc_comment: String,
+  pub name: String,
+  pub value: EnumValue,
+}
+
+/// Parts shared by the declaration of every enum
+pub struct SwiftEnumHeader<'a> {
+  pub doc_comment: &'a str,
+  pub access_level: &'a str,
+  pub enum_name: &'a str,
+  /// `, Equatable` ..
+  pub conformances: &'a str,
+}
+
+fn to_case_lines(cases: &[SwiftEnumCase]) -> String {
+  cases
+    .iter()
+    .map(|case| {
+      format!(
+        "{}{}case {}\n",
+        case.doc_comment,
+        swift_style::INDENT_SPACE,
+        case.name
+      )
+    })
+    .collect()
+}
+
+/// Enum with raw values of one type that keeps the values it does not declare
+/// in an `unknown` case
+pub fn to_swift_open_enum(
+  header: &SwiftEnumHeader,
+  raw_type: &str,
+  cases: &[SwiftEnumCase],
+) -> String {
+  let SwiftEnumHeader {
+    doc_comment,
+    access_level,
+    enum_name,
+    conformances,
+  } = header;
+  let indent = swift_style::INDENT_SPACE;
+  let indent2 = indent.repeat(2);
+
+  let case_lines = to_case_lines(cases);
+  let init_cases = cases
+    .iter()
+    .map(|case| {
+      format!(
+        "{indent2}case {}: self = .{}\n",
+        case.value.to_literal(),
+        case.name
+      )
+    })
+    .collect::<String>();
+  let raw_value_cases = cases
+    .iter()
+    .map(|case| {
+      format!(
+        "{indent2}case .{}: return {}\n",
+        case.name,
+        case.value.to_literal()
+      )
+    })
+    .collect::<String>();
+
+  format!(
+    "{doc_comment}{access_level}enum {enum_name}: RawRepresentable, Codable, Hashable{conformances} {{
+{case_lines}{indent}case unknown({raw_type})
+
+{indent}{access_level}init(rawValue: {raw_type}) {{
+{indent2}switch rawValue {{
+{init_cases}{indent2}default: self = .unknown(rawValue)
+{indent2}}}
+{indent}}}
+
+{indent}{access_level}var rawValue: {raw_type} {{
+{indent2}switch self {{
+{raw_value_cases}{indent2}case .unknown(let rawValue): return rawValue
+{indent2}}}
+{indent}}}
+}}
+"
+  )
+}
+
+/// Enum mixing string and number raw values, decoded from either and keeping
+/// the values it does not declare in `unknownNumber` / `unknownString` cases
+/// when `has_unknown_case`
+pub fn to_swift_mixed_enum(
+  header: &SwiftEnumHeader,
+  cases: &[SwiftEnumCase],
+  has_unknown_case: bool,
+) -> String {
+  let SwiftEnumHeader {
+    doc_comment,
+    access_level,
+    enum_name,
+    conformances,
+  } = header;
+  let indent = swift_style::INDENT_SPACE;
+  let indent2 = indent.repeat(2);
+  let indent3 = indent.repeat(3);
+
+  let mut case_lines = to_case_lines(cases);
+  if has_unknown_case {
+    case_lines.push_str(&format!(
+      "{indent}case unknownNumber(Double)\n{indent}case unknownString(String)\n"
+    ));
+  }
+
+  let decode_cases = |is_number: bool| {
+    cases
+      .iter()
+      .filter(|case| matches!(case.value, EnumValue::Number(_)) == is_number)
+      .map(|case| {
+        format!(
+          "{indent3}case {}: self = .{}\n",
+          case.value.to_literal(),
+          case.name
+        )
+      })
+      .collect::<String>()
+  };
+  let decode_default = |unknown_case: &str| {
+    if has_unknown_case {
+      format!("self = .{unknown_case}(value)")
+    } else {
+      format!(
+        "throw DecodingError.dataCorruptedError(in: container, debugDescription: \"Unknown {enum_name} value \\(value)\")"
+      )
+    }
+  };
+
+  let mut encode_cases = cases
+    .iter()
+    .map(|case| {
+      format!(
+        "{indent2}case .{}: try container.encode({})\n",
+        case.name,
+        case.value.to_literal()
+      )
+    })
+    .collect::<String>();
+  if has_unknown_case {
+    encode_cases.push_str(&format!(
+      "{indent2}case .unknownNumber(let value): try container.encode(value)\n\
+       {indent2}case .unknownString(let value): try container.encode(value)\n"
+    ));
+  }
+
+  // cases with a payload cannot be listed
+  let case_iterable = if has_unknown_case {
+    ""
+  } else {
+    ", CaseIterable"
+  };
+
+  format!(
+    "{doc_comment}{access_level}enum {enum_name}: Codable, Hashable{case_iterable}{conformances} {{
+{case_lines}
+{indent}{access_level}init(from decoder: Decoder) throws {{
+{indent2}let container = try decoder.singleValueContainer()
+{indent2}if let value = try? container.decode(Double.self) {{
+{indent3}switch value {{
+{}{indent3}default: {}
+{indent3}}}
+{indent2}}} else {{
+{indent3}let value = try container.decode(String.self)
+{indent3}switch value {{
+{}{indent3}default: {}
+{indent3}}}
+{indent2}}}
+{indent}}}
+
+{indent}{access_level}func encode(to encoder: Encoder) throws {{
+{indent2}var container = encoder.singleValueContainer()
+{indent2}switch self {{
+{encode_cases}{indent2}}}
+{indent}}}
+}}
+",
+    decode_cases(true),
+    decode_default("unknownNumber"),
+    decode_cases(false),
+    decode_default("unknownString"),
+  )
+}
@@ -1,6 +1,6 @@
-use oxc_ast::ast::{Expression, TSEnumDeclaration};
+use oxc_ast::ast::TSEnumDeclaration;
 
-use crate::languages::shared::enum_trait::GetEnumDisplayValue;
+use crate::languages::shared::enum_values::{EnumValue, EnumValues};
 
 pub trait SwiftEnumDisplayType {
   fn to_swift_enum_display_type(&self) -> String;
@@ -8,26 +8,20 @@ pub trait SwiftEnumDisplayType {
 
 impl SwiftEnumDisplayType for TSEnumDeclaration<'_> {
   fn to_swift_enum_display_type(&self) -> String {
-    let all_enum_string = self
-      .members
+    let values = self.get_enum_values();
+    let all_enum_string = values
       .iter()
-      .all(|x| matches!(x.initializer, Some(Expression::StringLiteral(_))));
+      .all(|x| matches!(x, Some(EnumValue::String(_))));
 
     if all_enum_string {
       return "String".to_string();
     }
 
-    let all_enum_double = self.members.iter().all(|x| {
-      matches!(x.initializer, Some(Expression::NumericLiteral(_)))
-        && x
-          .initializer
-          .as_ref()
-          .expect("Unable to get enum initializer value for get_enum_display_type")
-          .get_enum_display_value()
-          .contains(".")
-    });
+    let any_enum_double = values
+      .iter()
+      .any(|x| matches!(x, Some(EnumValue::Number(value)) if value.fract() != 0.0));
 
-    if all_enum_double {
+    if any_enum_double {
       return "Double".to_string();
     }
 
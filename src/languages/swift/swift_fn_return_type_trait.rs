@@ -1,32 +1,57 @@
 use oxc_ast::ast::TSType;
 
-use crate::languages::shared::is_async_trait::IsAsyncType;
+use crate::{
+  config::transform_config::SwiftAsyncStyle,
+  languages::shared::{is_async_trait::IsAsyncType, transform_context::TransformContext},
+};
 
-use super::swift_type_trait::SwiftType;
+use super::{swift_imports::import_swift_type, swift_type_trait::SwiftType};
 
 pub trait SwiftFunctionReturnType {
-  fn to_swift_fn_return_type(&self) -> String;
+  fn to_swift_fn_return_type(&self, ctx: &TransformContext) -> String;
 }
 
 impl SwiftFunctionReturnType for TSType<'_> {
-  fn to_swift_fn_return_type(&self) -> String {
+  fn to_swift_fn_return_type(&self, ctx: &TransformContext) -> String {
     match self {
       TSType::TSStringKeyword(_) => " -> String".to_string(),
-      TSType::TSNumberKeyword(_) => " -> Double".to_string(),
       TSType::TSBooleanKeyword(_) => " -> Bool".to_string(),
       TSType::TSVoidKeyword(_) => " -> Void".to_string(),
-      TSType::TSTypeReference(val) => {
-        let type_name = val.to_swift_type();
-        if val.is_async_type() {
-          format!(" async throws -> {type_name}")
-        } else {
-          format!(" -> {type_name}")
-        }
-      }
       TSType::TSFunctionType(val) => {
-        format!(" -> {}", val.to_swift_type())
+        format!(" -> {}", val.to_swift_type(ctx))
       }
-      _ => " -> Any".to_string(),
+      _ => format!(" -> {}", self.to_swift_type(ctx)),
+    }
+  }
+}
+
+/// Params and result of a function, `(id: String) async throws -> User`
+pub fn to_swift_fn_signature(
+  ctx: &TransformContext,
+  params: &str,
+  return_type: Option<&TSType>,
+) -> String {
+  match return_type {
+    Some(return_type) if return_type.is_async_type() => {
+      to_swift_async_signature(ctx, params, &return_type.to_swift_type(ctx))
+    }
+    Some(return_type) => format!("({params}){}", return_type.to_swift_fn_return_type(ctx)),
+    None => format!("({params})"),
+  }
+}
+
+/// Params and result of a function resolving to `result_type`, in the
+/// configured async style
+pub fn to_swift_async_signature(ctx: &TransformContext, params: &str, result_type: &str) -> String {
+  match ctx.config.swift.async_style {
+    SwiftAsyncStyle::AsyncThrows => format!("({params}) async throws -> {result_type}"),
+    SwiftAsyncStyle::CompletionHandler => {
+      let separator = if params.is_empty() { "" } else { ", " };
+      format!("({params}{separator}completion: @escaping (Result<{result_type}, Error>) -> Void)")
     }
+    SwiftAsyncStyle::Combine => format!(
+      "({params}) -> {}<{result_type}, Error>",
+      import_swift_type(ctx, "AnyPublisher")
+    ),
   }
 }
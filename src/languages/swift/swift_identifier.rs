@@ -0,0 +1,88 @@
+use oxc_ast::ast::PropertyKey;
+
+use crate::{
+  config::transform_config::NamingConvention,
+  languages::{
+    shared::{
+      identifier::{escape_reserved_word, to_valid_identifier},
+      transform_context::TransformContext,
+    },
+    swift::swift_type_trait::SwiftType,
+  },
+};
+
+/// Keywords that need backticks to be used as a name
+const SWIFT_RESERVED_WORDS: &[&str] = &[
+  "Any",
+  "Self",
+  "as",
+  "associatedtype",
+  "await",
+  "break",
+  "case",
+  "catch",
+  "class",
+  "continue",
+  "default",
+  "defer",
+  "deinit",
+  "do",
+  "else",
+  "enum",
+  "extension",
+  "fallthrough",
+  "false",
+  "fileprivate",
+  "for",
+  "func",
+  "guard",
+  "if",
+  "import",
+  "in",
+  "init",
+  "inout",
+  "internal",
+  "is",
+  "let",
+  "nil",
+  "open",
+  "operator",
+  "precedencegroup",
+  "private",
+  "protocol",
+  "public",
+  "repeat",
+  "rethrows",
+  "return",
+  "self",
+  "static",
+  "struct",
+  "subscript",
+  "super",
+  "switch",
+  "throw",
+  "throws",
+  "true",
+  "try",
+  "typealias",
+  "var",
+  "where",
+  "while",
+];
+
+/// A TS name as a Swift identifier, sanitized and escaped, `default` -> `` `default` ``
+pub fn to_swift_identifier(name: &str) -> String {
+  escape_reserved_word(&to_valid_identifier(name), SWIFT_RESERVED_WORDS)
+}
+
+/// The name of a property or method key in the configured naming `convention`
+pub fn to_swift_member_name(
+  ctx: &TransformContext,
+  key: &PropertyKey,
+  convention: Option<NamingConvention>,
+) -> String {
+  match (key.static_name(), convention) {
+    (Some(name), Some(convention)) => to_swift_identifier(&convention.apply(&name)),
+    _ => key.to_swift_type(ctx),
+  }
+}
@@ -0,0 +1,25 @@
+use crate::languages::shared::transform_context::TransformContext;
+
+/// Built-in types and the module they are imported from
+pub const SWIFT_IMPORTS: [(&str, &str); 7] = [
+  ("AnyCodable", "AnyCodable"),
+  ("AnyPublisher", "Combine"),
+  ("Data", "Foundation"),
+  ("Date", "Foundation"),
+  ("NSRegularExpression", "Foundation"),
+  ("Observable", "Observation"),
+  ("URL", "Foundation"),
+];
+
+/// How the emitted code refers to `type_name` of `SWIFT_IMPORTS`, qualified
+/// by its module when a declaration of the file has the same name, the module
+/// is imported either way
+pub fn import_swift_type(ctx: &TransformContext, type_name: &str) -> String {
+  let qualified_name = SWIFT_IMPORTS
+    .iter()
+    .find(|(name, _)| *name == type_name)
+    .map_or(type_name.to_string(), |(_, module_name)| {
+      format!("{module_name}.{type_name}")
+    });
+  ctx.import_type(type_name, &qualified_name)
+}
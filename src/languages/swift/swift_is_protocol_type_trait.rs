@@ -4,9 +4,9 @@ pub trait SwiftIsProtoclType {
   fn is_swift_protocol_type(&self) -> bool;
 }
 
-impl SwiftIsProtoclType for TSInterfaceDeclaration<'_> {
+impl SwiftIsProtoclType for TSSignature<'_> {
   fn is_swift_protocol_type(&self) -> bool {
-    let is_protocol = self.body.body.iter().any(|x| match x {
+    match self {
       TSSignature::TSMethodSignature(_) => true,
       TSSignature::TSPropertySignature(prop_sig) => {
         if let Some(type_annotation) = &prop_sig.type_annotation {
@@ -16,7 +16,12 @@ impl SwiftIsProtoclType for TSInterfaceDeclaration<'_> {
         }
       }
       _ => false,
-    });
-    is_protocol
+    }
+  }
+}
+
+impl SwiftIsProtoclType for TSInterfaceDeclaration<'_> {
+  fn is_swift_protocol_type(&self) -> bool {
+    self.body.body.iter().any(|x| x.is_swift_protocol_type())
   }
 }
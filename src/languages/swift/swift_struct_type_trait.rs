@@ -1,33 +1,152 @@
 use oxc_ast::ast::TSSignature;
 
-use crate::languages::swift::{swift_style, swift_type_trait::SwiftType};
+use crate::{
+  config::transform_config::SwiftRecordStyle,
+  languages::{
+    shared::{
+      identifier::unescape_identifier, member_name_trait::MemberName,
+      transform_context::TransformContext,
+    },
+    swift::{swift_identifier::to_swift_member_name, swift_style, swift_type_trait::SwiftType},
+  },
+};
 
 pub trait SwiftStructType {
-  fn to_swift_struct_type(&self) -> String;
+  fn to_swift_struct_type(&self, ctx: &TransformContext, access_level: &str) -> String;
 }
 
-impl SwiftStructType for TSSignature<'_> {
-  fn to_swift_struct_type(&self) -> String {
-    match self {
-      TSSignature::TSPropertySignature(prop_sig) => {
-        let type_annotation = prop_sig
-          .type_annotation
-          .as_ref()
-          .map(|annotation| annotation.type_annotation.to_swift_type())
-          .unwrap_or_default();
+/// A stored property of a struct
+pub struct SwiftProperty {
+  /// The TS name, used as the coding key
+  pub key: String,
+  pub name: String,
+  /// Type without the `?` of an optional property
+  pub type_annotation: String,
+  pub optional: bool,
+  /// `let` for `readonly` properties, `var` otherwise
+  pub readonly: bool,
+}
+
+pub fn get_swift_property(
+  ctx: &TransformContext,
+  signature: &TSSignature,
+) -> Option<SwiftProperty> {
+  let TSSignature::TSPropertySignature(prop_sig) = signature else {
+    return None;
+  };
+
+  let overrides = ctx.native_overrides(prop_sig.span, "swift");
+  let number_kind = ctx.member_number_kind(prop_sig.span, &signature.to_member_name());
+  let type_annotation = overrides.native_type.unwrap_or_else(|| {
+    ctx.with_number_kind(number_kind, || {
+      prop_sig
+        .type_annotation
+        .as_ref()
+        .map(|annotation| annotation.type_annotation.to_swift_type(ctx))
+        .unwrap_or_default()
+    })
+  });
+
+  Some(SwiftProperty {
+    key: signature.to_member_name(),
+    name: overrides.name.unwrap_or_else(|| {
+      to_swift_member_name(ctx, &prop_sig.key, ctx.config.swift.naming.properties)
+    }),
+    type_annotation,
+    optional: prop_sig.optional,
+    readonly: prop_sig.readonly,
+  })
+}
 
-        let prop_name = prop_sig.key.to_swift_type();
-        let optional = if prop_sig.optional { "?" } else { "" };
-        let swift_prop_sig = format!("{type_annotation}{optional}");
+/// Record style of a declaration, its `@swift-record-style` tag or the configured one
+pub fn get_swift_record_style(
+  ctx: &TransformContext,
+  record_style: Option<&str>,
+  type_name: &str,
+) -> SwiftRecordStyle {
+  let Some(record_style) = record_style else {
+    return ctx.config.swift.record_style;
+  };
 
+  serde_json::from_value(serde_json::Value::String(record_style.to_string())).unwrap_or_else(|_| {
+    ctx.report(format!(
+      "`{type_name}` has an unknown record style `{record_style}`, expected `struct`, `finalClass` or `observable`"
+    ));
+    ctx.config.swift.record_style
+  })
+}
+
+/// `CodingKeys` mapping the properties to their TS keys, `access_level` is
+/// `private ` or empty
+pub fn get_swift_coding_keys(properties: &[SwiftProperty], access_level: &str) -> String {
+  let indent = swift_style::INDENT_SPACE;
+  let coding_keys = properties
+    .iter()
+    .map(|property| {
+      if unescape_identifier(&property.name) == property.key {
+        format!("{indent}{indent}case {}\n", property.name)
+      } else {
         format!(
-          "{}let {}: {}",
-          swift_style::INDENT_SPACE,
-          prop_name,
-          swift_prop_sig
+          "{indent}{indent}case {} = \"{}\"\n",
+          property.name, property.key
         )
       }
-      _ => "".to_string(),
-    }
+    })
+    .collect::<String>();
+
+  format!("{indent}{access_level}enum CodingKeys: String, CodingKey {{\n{coding_keys}{indent}}}")
+}
+
+impl SwiftStructType for SwiftProperty {
+  fn to_swift_struct_type(&self, _ctx: &TransformContext, access_level: &str) -> String {
+    let optional = if self.optional { "?" } else { "" };
+    let swift_prop_sig = format!("{}{optional}", self.type_annotation);
+
+    let binding = if self.readonly { "let" } else { "var" };
+
+    format!(
+      "{}{access_level}{binding} {}: {}",
+      swift_style::INDENT_SPACE,
+      self.name,
+      swift_prop_sig
+    )
+  }
+}
+
+/// Initializer taking every property, optional ones default to `nil`, used
+/// where the memberwise init is missing or not visible enough
+pub fn get_swift_init(
+  properties: &[SwiftProperty],
+  additional_value_type: Option<&str>,
+  access_level: &str,
+) -> String {
+  let indent = swift_style::INDENT_SPACE;
+  let mut params = properties
+    .iter()
+    .map(|property| {
+      if property.optional {
+        format!("{}: {}? = nil", property.name, property.type_annotation)
+      } else {
+        format!("{}: {}", property.name, property.type_annotation)
+      }
+    })
+    .collect::<Vec<_>>();
+  let mut assignments = properties
+    .iter()
+    .map(|property| format!("{indent}{indent}self.{0} = {0}\n", property.name))
+    .collect::<String>();
+
+  if let Some(value_type) = additional_value_type {
+    params.push(format!(
+      "additionalProperties: [String: {value_type}] = [:]"
+    ));
+    assignments.push_str(&format!(
+      "{indent}{indent}self.additionalProperties = additionalProperties\n"
+    ));
   }
+
+  format!(
+    "{indent}{access_level}init({}) {{\n{assignments}{indent}}}",
+    params.join(", ")
+  )
 }
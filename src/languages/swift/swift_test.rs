@@ -5,7 +5,38 @@ mod tests {
   use oxc_parser::{ParseOptions, Parser};
   use oxc_span::SourceType;
 
-  use crate::languages::swift::swift_transformer::SwiftTransformer;
+  use crate::{
+    config::transform_config::{
+      NamingConvention, NamingConventions, SwiftAccessLevel, SwiftAsyncStyle, SwiftRecordStyle,
+      TransformConfig,
+    },
+    languages::{
+      shared::transform_context::TransformContext, swift::swift_transformer::SwiftTransformer,
+    },
+  };
+
+  fn transform(source_text: &str, config: &TransformConfig) -> String {
+    transform_with_diagnostics(source_text, config).0
+  }
+
+  fn transform_with_diagnostics(
+    source_text: &str,
+    config: &TransformConfig,
+  ) -> (String, Vec<String>) {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, source_text, source_type)
+      .with_options(ParseOptions {
+        ..ParseOptions::default()
+      })
+      .parse();
+
+    let program = parser_ret.program;
+    let ctx = TransformContext::new(&program, config);
+    let transformed_code = SwiftTransformer::transform(&program, &ctx);
+    (transformed_code, ctx.take_diagnostics())
+  }
 
   #[test]
   fn it_converts_to_swift_type() {
@@ -19,17 +50,8 @@ mod tests {
       readonly name: string;
     }
     "#;
-    let source_type = SourceType::ts();
-    let allocator = Allocator::default();
-
-    let parser_ret = Parser::new(&allocator, source_text, source_type)
-      .with_options(ParseOptions {
-        ..ParseOptions::default()
-      })
-      .parse();
-
-    let program = parser_ret.program;
-    let transformed_code = SwiftTransformer::transform(&program);
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
     println!("{transformed_code}");
 
     let expected_code = r#"protocol HelloWorld {
@@ -37,10 +59,1135 @@ mod tests {
   func getUser() -> User
 }
 
-struct User {
+struct User: Codable {
+  let name: String
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_jsdoc_to_swift_doc_comments() {
+    let source_text = r#"
+    /**
+     * Loads users from the backend
+     * @see https://example.com/docs
+     */
+    export interface UserService {
+      /**
+       * Fetches a user by id
+       * @param {string} userId - the user id
+       * @returns the matching user
+       * @throws when the user does not exist
+       */
+      getUser(userId: string): User;
+    }
+
+    enum Currency {
+      /** US dollar */
+      USD = "usd"
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"/// Loads users from the backend
+///
+/// - SeeAlso: https://example.com/docs
+public protocol UserService {
+  /// Fetches a user by id
+  ///
+  /// - Parameter userId: the user id
+  /// - Returns: the matching user
+  /// - Throws: when the user does not exist
+  func getUser(userId: String) -> User
+}
+
+enum Currency: String, Codable, CaseIterable { 
+  /// US dollar
+  case USD = "usd"
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_deprecated_tags_to_available_attributes() {
+    let source_text = r#"
+    interface User {
+      /** @deprecated use "displayName" instead */
+      readonly name: string;
+    }
+
+    /** @deprecated */
+    enum Currency {
+      USD = "usd"
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"struct User: Codable {
+  @available(*, deprecated, message: "use \"displayName\" instead")
+  let name: String
+}
+
+@available(*, deprecated)
+enum Currency: String, Codable, CaseIterable { 
+  case USD = "usd"
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_jsdoc_native_overrides() {
+    let source_text = r#"
+    /**
+     * @swift-name Account
+     * @native-conformance Hashable, Sendable
+     */
+    interface User {
+      /** @native-type Int */
+      readonly age: number;
+      /** @swift-name identifier */
+      readonly id: string;
+      /** @native-ignore */
+      readonly internalFlag: boolean;
+      readonly avatar: Image;
+    }
+
+    /** @native-type UIImage */
+    interface Image {
+      readonly url: string;
+    }
+
+    /** @native-ignore */
+    interface Internal {
+      readonly secret: string;
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"struct Account: Codable, Hashable, Sendable {
+  let age: Int
+  let identifier: String
+  let avatar: UIImage
+
+  enum CodingKeys: String, CodingKey {
+    case age
+    case identifier = "id"
+    case avatar
+  }
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_type_mappings_with_imports() {
+    let source_text = r#"
+    interface Invoice {
+      readonly total: Decimal;
+      readonly lines: Page<Decimal>;
+    }
+    "#;
+    let config: TransformConfig = serde_json::from_str(
+      r#"{
+        "swift": {
+          "typeMappings": {
+            "Decimal": { "type": "Decimal", "imports": ["Foundation"] },
+            "Page": { "type": "PagedResult<$0, Never>" }
+          }
+        }
+      }"#,
+    )
+    .unwrap();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import Foundation
+
+struct Invoice: Codable {
+  let total: Decimal
+  let lines: PagedResult<Decimal, Never>
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_well_known_types() {
+    let source_text = r#"
+    interface Attachment {
+      readonly createdAt: Date;
+      readonly bytes: Uint8Array;
+      readonly size: bigint;
+      readonly link: URL;
+      readonly metadata: unknown;
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import AnyCodable
+import Foundation
+
+struct Attachment: Codable {
+  let createdAt: Date
+  let bytes: Data
+  let size: Int64
+  let link: URL
+  let metadata: AnyCodable
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_imports_only_the_types_the_code_refers_to() {
+    let source_text = r#"
+    /** Picks a Date, the URL of the Data source is optional */
+    interface Picker {
+      readonly label: string;
+    }
+
+    interface Upload {
+      readonly bytes: Uint8Array;
+    }
+
+    interface Data {
+      readonly name: string;
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import Foundation
+
+/// Picks a Date, the URL of the Data source is optional
+struct Picker: Codable {
+  let label: String
+}
+
+struct Upload: Codable {
+  let bytes: Foundation.Data
+}
+
+struct Data: Codable {
+  let name: String
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_infers_integer_numbers() {
+    let source_text = r#"
+    type Int = number;
+
+    interface Inbox {
+      readonly userId: number;
+      /** @integer */
+      readonly pages: number[];
+      readonly counts: Record<string, Int>;
+      readonly score: number;
+      getMessage(index: number, ratio: number): Promise<Int>;
+    }
+    "#;
+    let config = TransformConfig {
+      integer_name_patterns: vec!["*Id".to_string(), "index".to_string()],
+      ..TransformConfig::default()
+    };
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"protocol Inbox {
+  var userId: Int { get }
+  var pages: [Int] { get }
+  var counts: [String: Int] { get }
+  var score: Double { get }
+  func getMessage(index: Int, ratio: Double) async throws -> Int
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_matches_name_patterns_against_properties_and_params_only() {
+    let source_text = r#"
+    interface Catalog {
+      readonly itemCount: number;
+      itemCount(shelfId: number): number;
+      /** @int */
+      pageCount(): number;
+    }
+    "#;
+    let config = TransformConfig {
+      integer_name_patterns: vec!["*Count".to_string(), "*Id".to_string()],
+      ..TransformConfig::default()
+    };
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"protocol Catalog {
+  var itemCount: Int { get }
+  func itemCount(shelfId: Int) -> Double
+  func pageCount() -> Int
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_converts_branded_types_to_raw_representable_structs() {
+    let source_text = r#"
+    /** Identifies a user */
+    export type UserId = string & { readonly __brand: "UserId" };
+    type Cents = number & { readonly __brand: "Cents" };
+
+    interface Order {
+      readonly buyer: UserId;
+      readonly total: Cents;
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"/// Identifies a user
+public struct UserId: RawRepresentable, Codable, Hashable {
+  public let rawValue: String
+  public init(rawValue: String) { self.rawValue = rawValue }
+}
+
+struct Cents: RawRepresentable, Codable, Hashable {
+  let rawValue: Double
+}
+
+struct Order: Codable {
+  let buyer: UserId
+  let total: Cents
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_merges_intersection_types() {
+    let source_text = r#"
+    interface User {
+      readonly id: string;
+      readonly name: string;
+    }
+
+    interface Reader {
+      read(): string;
+    }
+
+    interface Writer {
+      write(text: string): void;
+    }
+
+    type AdminUser = User & { readonly permissions: string[]; readonly id: number };
+    type ReadWriter = Reader & Writer;
+    "#;
+    let config = TransformConfig::default();
+    let (transformed_code, diagnostics) = transform_with_diagnostics(source_text, &config);
+
+    let expected_code = r#"struct User: Codable {
+  let id: String
+  let name: String
+}
+
+protocol Reader {
+  func read() -> String
+}
+
+protocol Writer {
+  func write(text: String) -> Void
+}
+
+struct AdminUser: Codable {
+  let id: String
+  let name: String
+  let permissions: [String]
+}
+
+typealias ReadWriter = Reader & Writer
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`AdminUser` declares `id` as both `string` and `number`, keeping `string`"]
+    );
+  }
+
+  #[test]
+  fn it_transforms_tuple_types() {
+    let source_text = r#"
+    type Point = [x: number, y: number];
+
+    interface Order {
+      readonly range: [number, number];
+      readonly tags: [...string[]];
+      readonly rest: [string, ...number[]];
+    }
+    "#;
+    let config = TransformConfig::default();
+    let (transformed_code, diagnostics) = transform_with_diagnostics(source_text, &config);
+
+    let expected_code = r#"typealias Point = (x: Double, y: Double)
+
+struct Order: Codable {
+  let range: (Double, Double)
+  let tags: [String]
+  let rest: [Any]
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["Swift tuples have no rest elements, `[string, ...number[]]` is emitted as `[Any]`"]
+    );
+  }
+
+  #[test]
+  fn it_transforms_index_signatures() {
+    let source_text = r#"
+    interface Headers {
+      [key: string]: string;
+    }
+
+    export interface Metadata {
+      readonly version: number;
+      readonly label?: string;
+      readonly [key: string]: number;
+    }
+
+    interface Scores {
+      readonly byIndex: { [index: number]: boolean };
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"typealias Headers = [String: String]
+
+public struct Metadata: Codable {
+  public let version: Double
+  public let label: String?
+  public let additionalProperties: [String: Double]
+
+  public init(version: Double, label: String? = nil, additionalProperties: [String: Double] = [:]) {
+    self.version = version
+    self.label = label
+    self.additionalProperties = additionalProperties
+  }
+
+  private enum CodingKeys: String, CodingKey {
+    case version
+    case label
+  }
+
+  private struct AdditionalPropertyKey: CodingKey {
+    let stringValue: String
+    var intValue: Int? { nil }
+    init(stringValue: String) { self.stringValue = stringValue }
+    init?(intValue: Int) { nil }
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    version = try container.decode(Double.self, forKey: .version)
+    label = try container.decodeIfPresent(String.self, forKey: .label)
+    let additionalContainer = try decoder.container(keyedBy: AdditionalPropertyKey.self)
+    var additionalProperties: [String: Double] = [:]
+    for key in additionalContainer.allKeys where CodingKeys(stringValue: key.stringValue) == nil {
+      additionalProperties[key.stringValue] = try additionalContainer.decode(Double.self, forKey: key)
+    }
+    self.additionalProperties = additionalProperties
+  }
+
+  public func encode(to encoder: Encoder) throws {
+    var container = encoder.container(keyedBy: CodingKeys.self)
+    try container.encode(version, forKey: .version)
+    try container.encodeIfPresent(label, forKey: .label)
+    var additionalContainer = encoder.container(keyedBy: AdditionalPropertyKey.self)
+    for (key, value) in additionalProperties {
+      try additionalContainer.encode(value, forKey: AdditionalPropertyKey(stringValue: key))
+    }
+  }
+}
+
+struct Scores: Codable {
+  let byIndex: [Int: Bool]
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_counts_up_implicit_enum_values_after_an_initializer() {
+    let source_text = r#"
+    enum Level {
+      Low = 5,
+      Medium,
+      High,
+      Off = -1,
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"enum Level: Int, Codable, CaseIterable { 
+  case Low = 5
+  case Medium = 6
+  case High = 7
+  case Off = -1
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_evaluates_constant_enum_expressions() {
+    let source_text = r#"
+    enum Flags {
+      None = 0,
+      Read = 1 << 0,
+      Write = 1 << 1,
+      ReadWrite = Read | Flags.Write,
+      Mask = ~0 >>> 28,
+    }
+
+    enum Ratio {
+      Half = 0.5,
+      Whole = Half * 2,
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"enum Flags: Int, Codable, CaseIterable { 
+  case None = 0
+  case Read = 1
+  case Write = 2
+  case ReadWrite = 3
+  case Mask = 15
+}
+enum Ratio: Double, Codable, CaseIterable { 
+  case Half = 0.5
+  case Whole = 1
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_emits_enums_that_are_not_constant_without_values() {
+    let source_text = r#"
+    enum Prefix {
+      Api = "api",
+      Users = `${"x"}`,
+    }
+    "#;
+    let (transformed_code, diagnostics) =
+      transform_with_diagnostics(source_text, &TransformConfig::default());
+
+    let expected_code = r#"enum Prefix: Int, Codable, CaseIterable { 
+  case Api
+  case Users
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+    assert_eq!(
+      diagnostics,
+      vec!["`Prefix` has members that are not constant, its cases are emitted without values"]
+    );
+  }
+
+  #[test]
+  fn it_transforms_flag_enums() {
+    let source_text = r#"
+    export enum Permissions {
+      /** Can read */
+      Read = 1 << 0,
+      Write = 1 << 1,
+      All = Read | Write,
+    }
+
+    /** @flags */
+    enum Style {
+      Plain,
+      Bold,
+    }
+
+    enum Level {
+      Low = 1,
+      Medium = 2,
+      High = 3,
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"public struct Permissions: OptionSet, Codable, Hashable {
+  public let rawValue: Int
+  public init(rawValue: Int) { self.rawValue = rawValue }
+
+  /// Can read
+  public static let Read = Permissions(rawValue: 1)
+  public static let Write = Permissions(rawValue: 2)
+  public static let All = Permissions(rawValue: 3)
+}
+struct Style: OptionSet, Codable, Hashable {
+  let rawValue: Int
+
+  static let Plain = Style(rawValue: 0)
+  static let Bold = Style(rawValue: 1)
+}
+enum Level: Int, Codable, CaseIterable { 
+  case Low = 1
+  case Medium = 2
+  case High = 3
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_mixed_enums() {
+    let source_text = r#"
+    enum Answer {
+      No = 0,
+      Yes = "yes",
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"enum Answer: Codable, Hashable, CaseIterable {
+  case No
+  case Yes
+
+  init(from decoder: Decoder) throws {
+    let container = try decoder.singleValueContainer()
+    if let value = try? container.decode(Double.self) {
+      switch value {
+      case 0: self = .No
+      default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown Answer value \(value)")
+      }
+    } else {
+      let value = try container.decode(String.self)
+      switch value {
+      case "yes": self = .Yes
+      default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown Answer value \(value)")
+      }
+    }
+  }
+
+  func encode(to encoder: Encoder) throws {
+    var container = encoder.singleValueContainer()
+    switch self {
+    case .No: try container.encode(0)
+    case .Yes: try container.encode("yes")
+    }
+  }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_adds_unknown_cases_to_open_enums() {
+    let source_text = r#"
+    enum Currency {
+      Usd = "usd",
+      Eur = "eur",
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.unknown_enum_case = true;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"enum Currency: RawRepresentable, Codable, Hashable {
+  case Usd
+  case Eur
+  case unknown(String)
+
+  init(rawValue: String) {
+    switch rawValue {
+    case "usd": self = .Usd
+    case "eur": self = .Eur
+    default: self = .unknown(rawValue)
+    }
+  }
+
+  var rawValue: String {
+    switch self {
+    case .Usd: return "usd"
+    case .Eur: return "eur"
+    case .unknown(let rawValue): return rawValue
+    }
+  }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_adds_unknown_cases_to_mixed_enums() {
+    let source_text = r#"
+    enum Answer {
+      No = 0,
+      Yes = "yes",
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.unknown_enum_case = true;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"enum Answer: Codable, Hashable {
+  case No
+  case Yes
+  case unknownNumber(Double)
+  case unknownString(String)
+
+  init(from decoder: Decoder) throws {
+    let container = try decoder.singleValueContainer()
+    if let value = try? container.decode(Double.self) {
+      switch value {
+      case 0: self = .No
+      default: self = .unknownNumber(value)
+      }
+    } else {
+      let value = try container.decode(String.self)
+      switch value {
+      case "yes": self = .Yes
+      default: self = .unknownString(value)
+      }
+    }
+  }
+
+  func encode(to encoder: Encoder) throws {
+    var container = encoder.singleValueContainer()
+    switch self {
+    case .No: try container.encode(0)
+    case .Yes: try container.encode("yes")
+    case .unknownNumber(let value): try container.encode(value)
+    case .unknownString(let value): try container.encode(value)
+    }
+  }
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_escapes_reserved_words_and_invalid_names() {
+    let source_text = r#"
+    interface Query {
+      readonly default: string;
+      readonly in: number;
+      readonly "content-type": string;
+      readonly "1st": boolean;
+      find(object: string, is: boolean): void;
+    }
+
+    interface Options {
+      readonly class: string;
+      readonly "max-age"?: number;
+    }
+
+    enum Header {
+      "content-type" = "Content-Type",
+      default = "default",
+    }
+    "#;
+    let config = TransformConfig::default();
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"protocol Query {
+  var `default`: String { get }
+  var `in`: Double { get }
+  var contentType: String { get }
+  var _1st: Bool { get }
+  func find(object: String, `is`: Bool) -> Void
+}
+
+struct Options: Codable {
+  let `class`: String
+  let maxAge: Double?
+
+  enum CodingKeys: String, CodingKey {
+    case `class`
+    case maxAge = "max-age"
+  }
+}
+
+enum Header: String, Codable, CaseIterable { 
+  case contentType = "Content-Type"
+  case `default` = "default"
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_escapes_declaration_and_statement_keywords() {
+    let source_text = r#"
+    interface Grammar {
+      readonly var: string;
+      readonly while: boolean;
+      readonly where: string;
+      readonly typealias: string;
+      readonly precedencegroup: number;
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"struct Grammar: Codable {
+  let `var`: String
+  let `while`: Bool
+  let `where`: String
+  let `typealias`: String
+  let `precedencegroup`: Double
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_keeps_wire_keys_of_renamed_members() {
+    let source_text = r#"
+    interface Session {
+      readonly access_token: string;
+      readonly "expires-in": number;
+      readonly scope: string;
+    }
+
+    enum Grant {
+      authorization_code = "authorization_code",
+      refresh_token = "refresh_token",
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.naming.properties = Some(NamingConvention::CamelCase);
+    config.swift.naming.enum_cases = Some(NamingConvention::CamelCase);
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"struct Session: Codable {
+  let accessToken: String
+  let expiresIn: Double
+  let scope: String
+
+  enum CodingKeys: String, CodingKey {
+    case accessToken = "access_token"
+    case expiresIn = "expires-in"
+    case scope
+  }
+}
+
+enum Grant: String, Codable, CaseIterable { 
+  case authorizationCode = "authorization_code"
+  case refreshToken = "refresh_token"
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_naming_conventions() {
+    let source_text = r#"
+    interface user_profile {
+      readonly display_name: string;
+      readonly "home-page"?: string;
+      readonly status: account_status;
+      fetch_friends(): user_profile[];
+    }
+
+    interface order_item {
+      readonly item_id: string;
+      readonly owner: user_profile;
+    }
+
+    enum account_status {
+      ACTIVE = "active",
+      SUSPENDED = "suspended",
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.naming = NamingConventions {
+      types: Some(NamingConvention::PascalCase),
+      properties: Some(NamingConvention::CamelCase),
+      methods: Some(NamingConvention::CamelCase),
+      enum_cases: Some(NamingConvention::CamelCase),
+    };
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"protocol UserProfile {
+  var displayName: String { get }
+  var homePage: String? { get }
+  var status: AccountStatus { get }
+  func fetchFriends() -> [UserProfile]
+}
+
+struct OrderItem: Codable {
+  let itemId: String
+  let owner: UserProfile
+
+  enum CodingKeys: String, CodingKey {
+    case itemId = "item_id"
+    case owner
+  }
+}
+
+enum AccountStatus: String, Codable, CaseIterable { 
+  case active = "active"
+  case suspended = "suspended"
+}
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_access_level_to_members() {
+    let source_text = r#"
+    export interface User {
+      readonly id: string;
+      readonly nickname?: string;
+    }
+
+    export enum Role {
+      Admin = "admin",
+      Guest = "guest",
+    }
+
+    interface Draft {
+      readonly title: string;
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.access_level = SwiftAccessLevel::Package;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"package struct User: Codable {
+  package let id: String
+  package let nickname: String?
+
+  package init(id: String, nickname: String? = nil) {
+    self.id = id
+    self.nickname = nickname
+  }
+}
+
+package enum Role: String, Codable, CaseIterable { 
+  case Admin = "admin"
+  case Guest = "guest"
+}
+struct Draft: Codable {
+  let title: String
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_records_to_classes() {
+    let source_text = r#"
+    interface Draft {
+      readonly id: string;
+      title: string;
+      note?: string;
+    }
+
+    export interface Session {
+      readonly token: string;
+      expires_at: number;
+    }
+
+    /**
+     * @swift-record-style observable
+     * @native-conformance Codable
+     */
+    interface ProfileModel {
+      display_name: string;
+      isOnline: boolean;
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.record_style = SwiftRecordStyle::FinalClass;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import Observation
+
+final class Draft: Codable {
+  let id: String
+  var title: String
+  var note: String?
+
+  init(id: String, title: String, note: String? = nil) {
+    self.id = id
+    self.title = title
+    self.note = note
+  }
+}
+
+public final class Session: Codable {
+  public let token: String
+  public var expires_at: Double
+
+  public init(token: String, expires_at: Double) {
+    self.token = token
+    self.expires_at = expires_at
+  }
+}
+
+@Observable
+final class ProfileModel: Codable {
+  var display_name: String
+  var isOnline: Bool
+
+  init(display_name: String, isOnline: Bool) {
+    self.display_name = display_name
+    self.isOnline = isOnline
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case _display_name = "display_name"
+    case _isOnline = "isOnline"
+  }
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_applies_async_styles() {
+    let source_text = r#"
+    interface UserService {
+      readonly currentUser: Promise<User>;
+      getUser(id: string): Promise<User>;
+      logout(): Promise<void>;
+      onRefresh: (force: boolean) => Promise<boolean>;
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.async_style = SwiftAsyncStyle::CompletionHandler;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"protocol UserService {
+  func currentUser(completion: @escaping (Result<User, Error>) -> Void)
+  func getUser(id: String, completion: @escaping (Result<User, Error>) -> Void)
+  func logout(completion: @escaping (Result<Void, Error>) -> Void)
+  func onRefresh(force: Bool, completion: @escaping (Result<Bool, Error>) -> Void)
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+
+    config.swift.async_style = SwiftAsyncStyle::Combine;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"import Combine
+
+protocol UserService {
+  var currentUser: AnyPublisher<User, Error> { get }
+  func getUser(id: String) -> AnyPublisher<User, Error>
+  func logout() -> AnyPublisher<Void, Error>
+  func onRefresh(force: Bool) -> AnyPublisher<Bool, Error>
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_transforms_streams() {
+    let source_text = r#"
+    interface ChatClient {
+      readonly messages: AsyncIterable<Message>;
+      readonly presence: Observable<Presence>;
+      onTyping(listener: (e: TypingEvent) => void): () => void;
+      onDisconnect(listener: () => void): void;
+      send(text: string): void;
+    }
+    "#;
+    let mut config = TransformConfig::default();
+    config.swift.listener_streams = true;
+    let transformed_code = transform(source_text, &config);
+
+    let expected_code = r#"protocol ChatClient {
+  var messages: AsyncThrowingStream<Message, Error> { get }
+  var presence: AsyncThrowingStream<Presence, Error> { get }
+  var typingStream: AsyncStream<TypingEvent> { get }
+  var disconnectStream: AsyncStream<Void> { get }
+  func send(text: String) -> Void
+}
+
+"#;
+    assert_eq!(transformed_code, expected_code);
+  }
+
+  #[test]
+  fn it_makes_every_record_and_enum_codable() {
+    let source_text = r#"
+    /** @native-conformance Hashable */
+    interface Point {
+      readonly x: number;
+    }
+
+    /** @native-conformance Decodable */
+    interface Event {
+      readonly name: string;
+    }
+
+    /** @swift-record-style observable */
+    interface Counter {
+      count: number;
+    }
+
+    /** @native-conformance Codable, Sendable */
+    enum Currency {
+      USD = "usd",
+    }
+    "#;
+    let transformed_code = transform(source_text, &TransformConfig::default());
+
+    let expected_code = r#"import Observation
+
+struct Point: Codable, Hashable {
+  let x: Double
+}
+
+struct Event: Decodable {
   let name: String
 }
 
+@Observable
+final class Counter: Codable {
+  var count: Double
+
+  init(count: Double) {
+    self.count = count
+  }
+
+  enum CodingKeys: String, CodingKey {
+    case _count = "count"
+  }
+}
+
+enum Currency: String, Codable, CaseIterable, Sendable { 
+  case USD = "usd"
+}
 "#;
     assert_eq!(transformed_code, expected_code);
   }
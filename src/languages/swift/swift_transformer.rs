@@ -1,18 +1,37 @@
 use oxc_ast::ast::Program;
 
-use super::swift_type_trait::SwiftType;
+use std::collections::BTreeSet;
+
+use crate::languages::shared::transform_context::TransformContext;
+
+use super::{swift_imports::SWIFT_IMPORTS, swift_type_trait::SwiftType};
 
 pub struct SwiftTransformer;
 
 impl SwiftTransformer {
-  pub fn transform(ast_program: &Program) -> String {
+  pub fn transform(ast_program: &Program, ctx: &TransformContext) -> String {
     let mut output = String::new();
 
     for statement in &ast_program.body {
-      let statement_code = statement.to_swift_type();
+      let statement_code = statement.to_swift_type(ctx);
       output.push_str(&statement_code);
     }
 
-    output
+    let imported_types = ctx.take_imported_types();
+    let import_banners = SWIFT_IMPORTS
+      .iter()
+      .filter(|(type_name, _)| imported_types.contains(*type_name))
+      .map(|(_, module_name)| module_name.to_string())
+      .chain(ctx.take_mapping_imports())
+      .collect::<BTreeSet<_>>()
+      .iter()
+      .map(|import_path| format!("import {import_path}\n"))
+      .collect::<String>();
+
+    if import_banners.is_empty() {
+      output
+    } else {
+      format!("{import_banners}\n{output}")
+    }
   }
 }
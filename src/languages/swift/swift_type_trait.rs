@@ -1,31 +1,61 @@
 use oxc_ast::ast::{
   BindingPatternKind, Declaration, ExportNamedDeclaration, FormalParameters, PropertyKey,
-  Statement, TSEnumDeclaration, TSEnumMember, TSEnumMemberName, TSFunctionType,
-  TSInterfaceDeclaration, TSSignature, TSType, TSTypeReference,
+  Statement, TSEnumDeclaration, TSEnumMember, TSEnumMemberName, TSFunctionType, TSIndexSignature,
+  TSInterfaceDeclaration, TSIntersectionType, TSSignature, TSTupleType, TSType,
+  TSTypeAliasDeclaration, TSTypeReference,
 };
 
-use crate::languages::{
-  shared::{
-    enum_trait::{GetEnumDisplayValue, IsEnumWithInitializerType},
-    is_async_trait::IsAsyncType,
-  },
-  swift::{
-    swift_enum_display_type_trait::SwiftEnumDisplayType,
-    swift_fn_return_type_trait::SwiftFunctionReturnType, swift_struct_type_trait::SwiftStructType,
-    swift_style,
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+  config::transform_config::{SwiftAsyncStyle, SwiftRecordStyle, UnknownType},
+  languages::{
+    shared::{
+      branded_type_trait::BrandedType,
+      enum_values::{EnumValues, get_enum_values_or_report, is_heterogeneous},
+      flags_enum::get_flag_values,
+      identifier::unescape_identifier,
+      index_signature::{get_index_key_type, get_index_signature, is_dictionary_type},
+      intersection_members::{get_intersected_interfaces, get_merged_members},
+      is_async_trait::IsAsyncType,
+      member_name_trait::MemberName,
+      number_kind::NumberKind,
+      stream_types::{STREAM_TYPE_NAMES, get_listener_subscription},
+      transform_context::TransformContext,
+      tuple_elements::get_tuple_elements,
+      type_mapping_trait::{NativeTypeMapping, uses_type_name},
+    },
+    swift::{
+      swift_access_level::{get_swift_access_level, is_swift_open_class},
+      swift_additional_properties::get_swift_additional_properties_coding,
+      swift_doc_comment_trait::get_swift_doc_comment,
+      swift_enum_coding::{
+        SwiftEnumCase, SwiftEnumHeader, to_swift_mixed_enum, to_swift_open_enum,
+      },
+      swift_enum_display_type_trait::SwiftEnumDisplayType,
+      swift_fn_return_type_trait::{to_swift_async_signature, to_swift_fn_signature},
+      swift_identifier::{to_swift_identifier, to_swift_member_name},
+      swift_imports::import_swift_type,
+      swift_struct_type_trait::{
+        SwiftProperty, SwiftStructType, get_swift_coding_keys, get_swift_init, get_swift_property,
+        get_swift_record_style,
+      },
+      swift_style,
+    },
   },
 };
 
 use super::swift_is_protocol_type_trait::SwiftIsProtoclType;
 
 pub trait SwiftType {
-  fn to_swift_type(&self) -> String;
+  fn to_swift_type(&self, ctx: &TransformContext) -> String;
 }
 
 impl SwiftType for PropertyKey<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, _ctx: &TransformContext) -> String {
     match self {
-      PropertyKey::StaticIdentifier(id_name) => id_name.name.to_string(),
+      PropertyKey::StaticIdentifier(id_name) => to_swift_identifier(&id_name.name),
+      PropertyKey::StringLiteral(literal) => to_swift_identifier(&literal.value),
       PropertyKey::Identifier(id_name) => id_name.to_string(),
       _ => "unknown-PropertyKey".to_owned(),
     }
@@ -33,23 +63,38 @@ impl SwiftType for PropertyKey<'_> {
 }
 
 impl SwiftType for BindingPatternKind<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, _ctx: &TransformContext) -> String {
     match self {
-      BindingPatternKind::BindingIdentifier(val) => val.name.to_string(),
+      BindingPatternKind::BindingIdentifier(val) => to_swift_identifier(&val.name),
       _ => "unknown-BindingPatternKind".to_owned(),
     }
   }
 }
 
 impl SwiftType for TSTypeReference<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
     let type_name = self.type_name.to_string();
+    if let Some(type_mapping) = ctx.config.swift.type_mappings.get(&type_name) {
+      let type_args = self
+        .type_parameters
+        .as_ref()
+        .map(|x| {
+          x.params
+            .iter()
+            .map(|x| x.to_swift_type(ctx))
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      ctx.add_mapping_imports(&type_mapping.imports);
+      return type_mapping.to_native_type(&type_args, "<", ">");
+    }
+
     match type_name.as_str() {
       "Promise" => self
         .type_parameters
         .as_ref()
         .and_then(|x| x.params.first())
-        .map(|x| x.to_swift_type())
+        .map(|x| x.to_swift_type(ctx))
         .unwrap_or_else(|| "Any".into()),
 
       "Array" | "ReadonlyArray" => format!(
@@ -58,7 +103,7 @@ impl SwiftType for TSTypeReference<'_> {
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.first())
-          .map(|x| x.to_swift_type())
+          .map(|x| x.to_swift_type(ctx))
           .unwrap_or_else(|| "Any".into())
       ),
 
@@ -67,14 +112,14 @@ impl SwiftType for TSTypeReference<'_> {
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.first())
-          .map(|x| x.to_swift_type())
+          .map(|x| x.to_swift_type(ctx))
           .unwrap_or_else(|| "Any".into());
 
         let val_str = self
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.get(1))
-          .map(|x| x.to_swift_type())
+          .map(|x| x.to_swift_type(ctx))
           .unwrap_or_else(|| "Any".into());
 
         format!("[{key_str}: {val_str}]")
@@ -85,21 +130,40 @@ impl SwiftType for TSTypeReference<'_> {
           .type_parameters
           .as_ref()
           .and_then(|x| x.params.first())
-          .map(|x| x.to_swift_type())
+          .map(|x| x.to_swift_type(ctx))
           .unwrap_or_else(|| "Any".into());
         format!("{type_name}<{val_str}>")
       }
 
-      _ => type_name,
+      "Date" | "URL" => import_swift_type(ctx, &type_name),
+      "Error" => type_name,
+      "Uint8Array" | "ArrayBuffer" => import_swift_type(ctx, "Data"),
+      // iterating and observing can fail
+      name if STREAM_TYPE_NAMES.contains(&name) => format!(
+        "AsyncThrowingStream<{}, Error>",
+        self
+          .type_parameters
+          .as_ref()
+          .and_then(|x| x.params.first())
+          .map(|x| x.to_swift_type(ctx))
+          .unwrap_or_else(|| "Any".into())
+      ),
+      "RegExp" => import_swift_type(ctx, "NSRegularExpression"),
+
+      _ => match ctx.integer_alias(&type_name) {
+        Some(NumberKind::Long) => "Int64".to_string(),
+        Some(_) => "Int".to_string(),
+        None => ctx.native_type_name(&type_name, "swift"),
+      },
     }
   }
 }
 
 impl SwiftType for TSFunctionType<'_> {
   /// this is invoked from second level functions
-  fn to_swift_type(&self) -> String {
-    let type_name = self.return_type.type_annotation.to_swift_type();
-    let fn_params = self.params.to_swift_type();
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    let type_name = self.return_type.type_annotation.to_swift_type(ctx);
+    let fn_params = self.params.to_swift_type(ctx);
 
     // Function types cannot have argument labels; use '_' before 'name'
     let ignore_arg_labels = if !fn_params.is_empty() { "_ " } else { "" };
@@ -109,43 +173,129 @@ impl SwiftType for TSFunctionType<'_> {
 }
 
 impl SwiftType for TSType<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
     match self {
       TSType::TSStringKeyword(_) => "String".to_string(),
-      TSType::TSNumberKeyword(_) => "Double".to_string(),
+      TSType::TSNumberKeyword(_) => match ctx.number_kind() {
+        NumberKind::Double => "Double".to_string(),
+        NumberKind::Int => "Int".to_string(),
+        NumberKind::Long => "Int64".to_string(),
+      },
       TSType::TSBooleanKeyword(_) => "Bool".to_string(),
       TSType::TSVoidKeyword(_) => "Void".to_string(),
       TSType::TSObjectKeyword(_) => "[String: Any]".to_string(),
-      TSType::TSTypeReference(ref_type) => ref_type.to_swift_type(),
-      TSType::TSFunctionType(fn_type) => fn_type.to_swift_type(),
+      TSType::TSBigIntKeyword(_) => "Int64".to_string(),
+      TSType::TSUnknownKeyword(_) | TSType::TSAnyKeyword(_) => {
+        match ctx.config.swift.unknown_type {
+          UnknownType::Json => import_swift_type(ctx, "AnyCodable"),
+          UnknownType::Any => "Any".to_string(),
+        }
+      }
+      TSType::TSTypeReference(ref_type) => ref_type.to_swift_type(ctx),
+      TSType::TSFunctionType(fn_type) => fn_type.to_swift_type(ctx),
       TSType::TSArrayType(array_type) => {
-        let el_type = array_type.element_type.to_swift_type();
+        let el_type = array_type.element_type.to_swift_type(ctx);
         format!("[{el_type}]")
       }
-      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_swift_type(),
+      TSType::TSTypeOperatorType(op_type) => op_type.type_annotation.to_swift_type(ctx),
+      TSType::TSTupleType(tuple_type) => tuple_type.to_swift_type(ctx),
+      TSType::TSTypeLiteral(type_literal) => {
+        let members = type_literal.members.iter().collect::<Vec<_>>();
+        match get_index_signature(&members) {
+          Some(index_sig) if is_dictionary_type(&members) => index_sig.to_swift_type(ctx),
+          _ => "Any".to_string(),
+        }
+      }
       _ => "Any".to_string(),
     }
   }
 }
 
+/// `[key: string]: T` as a dictionary, numeric keys are integers
+impl SwiftType for TSIndexSignature<'_> {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    let key_type = ctx.with_number_kind(NumberKind::Int, || {
+      get_index_key_type(self)
+        .map(|key_type| key_type.to_swift_type(ctx))
+        .unwrap_or_else(|| "String".to_string())
+    });
+    format!("[{key_type}: {}]", to_swift_index_value_type(ctx, self))
+  }
+}
+
+fn to_swift_index_value_type(ctx: &TransformContext, index_sig: &TSIndexSignature) -> String {
+  let number_kind = ctx.member_number_kind(index_sig.span, "");
+  ctx.with_number_kind(number_kind, || {
+    index_sig.type_annotation.type_annotation.to_swift_type(ctx)
+  })
+}
+
+impl SwiftType for TSTupleType<'_> {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    let elements = get_tuple_elements(self);
+
+    // `[...string[]]` is a plain array
+    if let [element] = elements.as_slice()
+      && element.rest
+    {
+      return element.element_type.to_swift_type(ctx);
+    }
+
+    if elements.iter().any(|x| x.rest) {
+      ctx.report(format!(
+        "Swift tuples have no rest elements, `{}` is emitted as `[Any]`",
+        ctx.source_text(self.span)
+      ));
+      return "[Any]".to_string();
+    }
+
+    let element_types = elements
+      .iter()
+      .map(|x| {
+        let optional = if x.optional { "?" } else { "" };
+        (
+          x.label.as_deref(),
+          format!("{}{optional}", x.element_type.to_swift_type(ctx)),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    match element_types.as_slice() {
+      // a single element tuple is the element itself, labels included
+      [(_, element_type)] => element_type.clone(),
+      _ => format!(
+        "({})",
+        element_types
+          .iter()
+          .map(|(label, element_type)| match label {
+            Some(label) => format!("{label}: {element_type}"),
+            None => element_type.clone(),
+          })
+          .collect::<Vec<_>>()
+          .join(", ")
+      ),
+    }
+  }
+}
+
 impl SwiftType for FormalParameters<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
     self
       .items
       .iter()
       .map(|param| {
-        let type_annotation = param
-          .pattern
-          .type_annotation
-          .as_ref()
-          .map(|t| t.type_annotation.to_swift_type())
-          .unwrap_or_else(|| "Any".to_string());
-
-        format!(
-          "{}: {}",
-          param.pattern.kind.to_swift_type(),
-          type_annotation
-        )
+        let param_name = param.pattern.kind.to_swift_type(ctx);
+        let number_kind = ctx.member_number_kind(param.span, &param_name);
+        let type_annotation = ctx.with_number_kind(number_kind, || {
+          param
+            .pattern
+            .type_annotation
+            .as_ref()
+            .map(|t| t.type_annotation.to_swift_type(ctx))
+            .unwrap_or_else(|| "Any".to_string())
+        });
+
+        format!("{param_name}: {type_annotation}")
       })
       .collect::<Vec<_>>()
       .join(", ")
@@ -153,41 +303,77 @@ impl SwiftType for FormalParameters<'_> {
 }
 
 impl SwiftType for TSSignature<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    let number_kind = match self {
+      TSSignature::TSMethodSignature(method_sig) => ctx.method_number_kind(method_sig.span),
+      _ => ctx.member_number_kind(self.span(), &self.to_member_name()),
+    };
+    ctx.with_number_kind(number_kind, || self.to_swift_member_type(ctx))
+  }
+}
+
+trait SwiftMemberType {
+  fn to_swift_member_type(&self, ctx: &TransformContext) -> String;
+}
+
+impl SwiftMemberType for TSSignature<'_> {
+  fn to_swift_member_type(&self, ctx: &TransformContext) -> String {
     match self {
       TSSignature::TSPropertySignature(prop_sig) => {
-        let prop_name = prop_sig.key.to_swift_type();
+        let overrides = ctx.native_overrides(prop_sig.span, "swift");
+        let prop_name = overrides.name.unwrap_or_else(|| {
+          to_swift_member_name(ctx, &prop_sig.key, ctx.config.swift.naming.properties)
+        });
         let optional = if prop_sig.optional { "?" } else { "" };
 
         // If property is a arrow function
-        if let Some(annotation) = prop_sig.type_annotation.as_ref() {
-          if let TSType::TSFunctionType(fn_type) = &annotation.type_annotation {
-            let fn_return_type = fn_type
-              .return_type
-              .type_annotation
-              .to_swift_fn_return_type();
+        if overrides.native_type.is_none()
+          && let Some(annotation) = prop_sig.type_annotation.as_ref()
+          && let TSType::TSFunctionType(fn_type) = &annotation.type_annotation
+        {
+          let fn_signature = to_swift_fn_signature(
+            ctx,
+            &fn_type.params.to_swift_type(ctx),
+            Some(&fn_type.return_type.type_annotation),
+          );
+
+          return format!(
+            "{}func {}{}{}",
+            swift_style::INDENT_SPACE,
+            prop_name,
+            fn_signature,
+            optional
+          );
+        }
 
-            let fn_params = fn_type.params.to_swift_type();
+        let type_annotation = overrides.native_type.unwrap_or_else(|| {
+          prop_sig
+            .type_annotation
+            .as_ref()
+            .map(|annotation| annotation.type_annotation.to_swift_type(ctx))
+            .unwrap_or_default()
+        });
 
+        let is_async = prop_sig.is_async_type();
+        match ctx.config.swift.async_style {
+          // a property cannot take the completion handler
+          SwiftAsyncStyle::CompletionHandler if is_async => {
+            return format!(
+              "{}func {prop_name}{}",
+              swift_style::INDENT_SPACE,
+              to_swift_async_signature(ctx, "", &type_annotation)
+            );
+          }
+          SwiftAsyncStyle::Combine if is_async => {
             return format!(
-              "{}func {}({}){}{}",
+              "{}var {prop_name}: {}<{type_annotation}, Error>{optional} {{ get }}",
               swift_style::INDENT_SPACE,
-              prop_name,
-              fn_params,
-              fn_return_type,
-              optional
+              import_swift_type(ctx, "AnyPublisher")
             );
           }
+          _ => {}
         }
 
-        let type_annotation = prop_sig
-          .type_annotation
-          .as_ref()
-          .map(|annotation| annotation.type_annotation.to_swift_type())
-          .unwrap_or_default();
-
-        let is_async = prop_sig.is_async_type();
-
         let get_set_value = if prop_sig.readonly || is_async {
           "get"
         } else {
@@ -206,21 +392,45 @@ impl SwiftType for TSSignature<'_> {
         )
       }
       TSSignature::TSMethodSignature(method_sig) => {
-        let params = method_sig.params.to_swift_type();
+        let overrides = ctx.native_overrides(method_sig.span, "swift");
+        if ctx.config.swift.listener_streams
+          && let Some(subscription) = get_listener_subscription(method_sig)
+        {
+          let event_type = subscription
+            .event_type
+            .map(|event_type| event_type.to_swift_type(ctx))
+            .unwrap_or_else(|| "Void".to_string());
+          let prop_name = overrides
+            .name
+            .unwrap_or_else(|| to_swift_identifier(&format!("{}Stream", subscription.event_name)));
+          return format!(
+            "{}var {prop_name}: AsyncStream<{event_type}> {{ get }}",
+            swift_style::INDENT_SPACE
+          );
+        }
 
-        let return_type = method_sig
-          .return_type
-          .as_ref()
-          .map(|r| r.type_annotation.to_swift_fn_return_type())
-          .unwrap_or_else(|| "".to_string());
+        let params = method_sig.params.to_swift_type(ctx);
+
+        let fn_signature = match overrides.native_type {
+          Some(native_type) if method_sig.is_async_type() => {
+            to_swift_async_signature(ctx, &params, &native_type)
+          }
+          Some(native_type) => format!("({params}) -> {native_type}"),
+          None => to_swift_fn_signature(
+            ctx,
+            &params,
+            method_sig.return_type.as_ref().map(|r| &r.type_annotation),
+          ),
+        };
 
-        let func_name = method_sig.key.to_swift_type();
+        let func_name = overrides.name.unwrap_or_else(|| {
+          to_swift_member_name(ctx, &method_sig.key, ctx.config.swift.naming.methods)
+        });
         format!(
-          "{}func {}({}){}",
+          "{}func {}{}",
           swift_style::INDENT_SPACE,
           func_name,
-          params,
-          return_type
+          fn_signature
         )
       }
       _ => "// unknown-signature".to_owned(),
@@ -229,112 +439,440 @@ impl SwiftType for TSSignature<'_> {
 }
 
 impl SwiftType for Declaration<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
     match self {
-      Declaration::TSInterfaceDeclaration(interface_decl) => interface_decl.to_swift_type(),
-      Declaration::TSEnumDeclaration(enum_decl) => enum_decl.to_swift_type(),
+      Declaration::TSInterfaceDeclaration(interface_decl) => interface_decl.to_swift_type(ctx),
+      Declaration::TSEnumDeclaration(enum_decl) => enum_decl.to_swift_type(ctx),
+      Declaration::TSTypeAliasDeclaration(alias_decl) => alias_decl.to_swift_type(ctx),
       _ => "// unknown-declaration".to_string(),
     }
   }
 }
 
 impl SwiftType for TSInterfaceDeclaration<'_> {
-  fn to_swift_type(&self) -> String {
-    let is_protocol = self.is_swift_protocol_type();
-    let interface_name = self.id.name.to_string();
-
-    if is_protocol {
-      let body_data = self
-        .body
-        .body
-        .iter()
-        .map(|signature| signature.to_swift_type())
-        .collect::<Vec<_>>()
-        .join("\n");
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    let members = self.body.body.iter().collect::<Vec<_>>();
+    to_swift_record_type(ctx, self.span, &self.id.name, &members)
+  }
+}
 
-      format!("protocol {interface_name} {{\n{body_data}\n}}\n\n")
+/// `conformances` led by `Codable`, `: Hashable` -> `: Codable, Hashable`
+fn with_codable_conformance(conformances: &str) -> String {
+  match conformances.strip_prefix(": ") {
+    Some(conformances) => format!(": Codable, {conformances}"),
+    None => ": Codable".to_string(),
+  }
+}
+
+/// Protocol or struct holding `members`, shared by interfaces and merged intersections
+fn to_swift_record_type(
+  ctx: &TransformContext,
+  span: Span,
+  type_name: &str,
+  members: &[&TSSignature],
+) -> String {
+  let overrides = ctx.native_overrides(span, "swift");
+  if overrides.is_ignored || overrides.native_type.is_some() {
+    return "".to_string();
+  }
+
+  let is_protocol = members.iter().any(|x| x.is_swift_protocol_type());
+  let interface_name = overrides
+    .name
+    .unwrap_or_else(|| ctx.declared_type_name(type_name, "swift"));
+  let conformances = if overrides.conformances.is_empty() {
+    "".to_string()
+  } else {
+    format!(": {}", overrides.conformances.join(", "))
+  };
+  let doc_comment = get_swift_doc_comment(ctx, span, "");
+  let access_level = get_swift_access_level(ctx, span);
+
+  let index_sig = get_index_signature(members);
+  if let Some(index_sig) = index_sig
+    && is_dictionary_type(members)
+  {
+    return format!(
+      "{doc_comment}{access_level}typealias {interface_name} = {}\n\n",
+      index_sig.to_swift_type(ctx)
+    );
+  }
+
+  if is_protocol {
+    let body_data = members
+      .iter()
+      .filter(|signature| !ctx.native_overrides(signature.span(), "swift").is_ignored)
+      .map(|signature| {
+        format!(
+          "{}{}",
+          get_swift_doc_comment(ctx, signature.span(), swift_style::INDENT_SPACE),
+          signature.to_swift_type(ctx)
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    format!(
+      "{doc_comment}{access_level}protocol {interface_name}{conformances} {{\n{body_data}\n}}\n\n"
+    )
+  } else {
+    let record_style =
+      get_swift_record_style(ctx, overrides.record_style.as_deref(), &interface_name);
+    let is_open_class = record_style != SwiftRecordStyle::Struct && is_swift_open_class(ctx, span);
+    let members = members
+      .iter()
+      .filter(|signature| !ctx.native_overrides(signature.span(), "swift").is_ignored)
+      .filter(|signature| !matches!(signature, TSSignature::TSIndexSignature(_)))
+      .map(|signature| (signature, get_swift_property(ctx, signature)))
+      .collect::<Vec<_>>();
+    let mut body_data = members
+      .iter()
+      .map(|(signature, property)| {
+        format!(
+          "{}{}",
+          get_swift_doc_comment(ctx, signature.span(), swift_style::INDENT_SPACE),
+          property
+            .as_ref()
+            .map(|property| property.to_swift_struct_type(ctx, access_level))
+            .unwrap_or_default()
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+    let properties = members
+      .into_iter()
+      .filter_map(|(_, property)| property)
+      .collect::<Vec<_>>();
+
+    // the keys the struct does not declare are collected by a custom `Codable`
+    let mut conformances = conformances;
+    if let Some(index_sig) = index_sig {
+      let value_type = to_swift_index_value_type(ctx, index_sig);
+
+      body_data.push_str(&format!(
+        "\n{}{access_level}let additionalProperties: [String: {value_type}]\n\n{}\n{}",
+        swift_style::INDENT_SPACE,
+        get_swift_init(&properties, Some(&value_type), access_level),
+        get_swift_additional_properties_coding(
+          &properties,
+          &value_type,
+          access_level,
+          is_open_class
+        )
+      ));
+      conformances = with_codable_conformance(&conformances);
     } else {
-      let body_data = self
-        .body
-        .body
+      // classes have no memberwise init, the one of a struct is internal
+      if record_style != SwiftRecordStyle::Struct || !access_level.is_empty() {
+        body_data.push_str(&format!(
+          "\n\n{}",
+          get_swift_init(&properties, None, access_level)
+        ));
+      }
+
+      // every record is `Codable`, unless a conformance already picks a direction
+      let is_codable = ["Codable", "Decodable", "Encodable"]
         .iter()
-        .map(|signature| signature.to_swift_struct_type())
-        .collect::<Vec<_>>()
-        .join("\n");
+        .any(|conformance| uses_type_name(&conformances, conformance));
+      if !is_codable {
+        conformances = with_codable_conformance(&conformances);
+      }
 
-      format!("struct {interface_name} {{\n{body_data}\n}}\n\n")
+      if record_style == SwiftRecordStyle::Observable {
+        // `@Observable` stores the properties as `_name`, which synthesized `Codable` uses
+        let storage_properties = properties
+          .iter()
+          .map(|property| SwiftProperty {
+            key: property.key.clone(),
+            name: format!("_{}", unescape_identifier(&property.name)),
+            type_annotation: property.type_annotation.clone(),
+            optional: property.optional,
+            readonly: property.readonly,
+          })
+          .collect::<Vec<_>>();
+        body_data.push_str(&format!(
+          "\n\n{}",
+          get_swift_coding_keys(&storage_properties, "")
+        ));
+      } else if properties
+        .iter()
+        .any(|property| unescape_identifier(&property.name) != property.key)
+      {
+        // renamed and sanitized properties keep their TS key
+        body_data.push_str(&format!("\n\n{}", get_swift_coding_keys(&properties, "")));
+      }
     }
+
+    let class_modifier = if is_open_class {
+      "open ".to_string()
+    } else {
+      format!("{access_level}final ")
+    };
+    let declaration = match record_style {
+      SwiftRecordStyle::Struct => format!("{access_level}struct"),
+      SwiftRecordStyle::FinalClass => format!("{class_modifier}class"),
+      SwiftRecordStyle::Observable => format!(
+        "@{}\n{class_modifier}class",
+        import_swift_type(ctx, "Observable")
+      ),
+    };
+    format!("{doc_comment}{declaration} {interface_name}{conformances} {{\n{body_data}\n}}\n\n")
   }
 }
 
 impl SwiftType for ExportNamedDeclaration<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
     self
       .declaration
       .as_ref()
-      .map(|d| d.to_swift_type())
-      .map(|d| {
-        if d.starts_with("//") {
-          d
-        } else {
-          format!("public {d}")
-        }
-      })
+      .map(|d| d.to_swift_type(ctx))
       .unwrap_or_else(|| "// unknown-export-named-declaration".to_string())
   }
 }
 
 impl SwiftType for TSEnumMember<'_> {
-  fn to_swift_type(&self) -> String {
-    match &self.id {
-      TSEnumMemberName::Identifier(enum_id) => enum_id.to_string(),
-      TSEnumMemberName::String(enum_string) => enum_string.to_string(),
-    }
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    ctx
+      .native_overrides(self.span, "swift")
+      .name
+      .unwrap_or_else(|| {
+        let name = match &self.id {
+          TSEnumMemberName::Identifier(enum_id) => enum_id.name.to_string(),
+          TSEnumMemberName::String(enum_string) => enum_string.value.to_string(),
+        };
+        let name = match ctx.config.swift.naming.enum_cases {
+          Some(convention) => convention.apply(&name),
+          None => name,
+        };
+        to_swift_identifier(&name)
+      })
   }
 }
 
 impl SwiftType for TSEnumDeclaration<'_> {
-  fn to_swift_type(&self) -> String {
-    let enum_name = self.id.to_string();
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    let overrides = ctx.native_overrides(self.span, "swift");
+    if overrides.is_ignored || overrides.native_type.is_some() {
+      return "".to_string();
+    }
 
-    if self.is_enum_with_initializer_type() {
+    let enum_name = overrides
+      .name
+      .unwrap_or_else(|| ctx.declared_type_name(&self.id.name, "swift"));
+    // every enum is `Codable` already
+    let conformances = overrides
+      .conformances
+      .iter()
+      .filter(|conformance| *conformance != "Codable")
+      .map(|conformance| format!(", {conformance}"))
+      .collect::<String>();
+    let doc_comment = get_swift_doc_comment(ctx, self.span, "");
+    let access_level = get_swift_access_level(ctx, self.span);
+
+    if let Some(flag_values) = get_flag_values(ctx, self) {
+      let indent = swift_style::INDENT_SPACE;
+      let static_members = self
+        .members
+        .iter()
+        .zip(flag_values)
+        .filter(|(x, _)| !ctx.native_overrides(x.span, "swift").is_ignored)
+        .map(|(x, flag_value)| {
+          format!(
+            "{}{indent}{access_level}static let {} = {enum_name}(rawValue: {flag_value})",
+            get_swift_doc_comment(ctx, x.span, indent),
+            x.to_swift_type(ctx)
+          )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+      // the memberwise init of a public struct is internal
+      let init = if access_level.is_empty() {
+        "".to_string()
+      } else {
+        format!("{indent}{access_level}init(rawValue: Int) {{ self.rawValue = rawValue }}\n")
+      };
+
+      return format!(
+        "{doc_comment}{access_level}struct {enum_name}: OptionSet, Codable, Hashable{conformances} {{\n{indent}{access_level}let rawValue: Int\n{init}\n{static_members}\n}}\n"
+      );
+    }
+
+    let has_unknown_case = ctx.config.swift.unknown_enum_case;
+    let enum_values = (self.has_enum_initializer() || has_unknown_case)
+      .then(|| get_enum_values_or_report(ctx, self, &enum_name))
+      .flatten();
+
+    if let Some(enum_values) = &enum_values
+      && (has_unknown_case || is_heterogeneous(enum_values))
+    {
+      let cases = self
+        .members
+        .iter()
+        .zip(enum_values)
+        .filter(|(x, _)| !ctx.native_overrides(x.span, "swift").is_ignored)
+        .map(|(x, enum_value)| SwiftEnumCase {
+          doc_comment: get_swift_doc_comment(ctx, x.span, swift_style::INDENT_SPACE),
+          name: x.to_swift_type(ctx),
+          value: enum_value.clone(),
+        })
+        .collect::<Vec<_>>();
+      let header = SwiftEnumHeader {
+        doc_comment: &doc_comment,
+        access_level,
+        enum_name: &enum_name,
+        conformances: &conformances,
+      };
+
+      return if is_heterogeneous(enum_values) {
+        to_swift_mixed_enum(&header, &cases, has_unknown_case)
+      } else {
+        to_swift_open_enum(&header, &self.to_swift_enum_display_type(), &cases)
+      };
+    }
+
+    if let Some(enum_values) = enum_values {
       let enum_cases: String = self
         .members
         .iter()
-        .map(|x| {
+        .zip(&enum_values)
+        .filter(|(x, _)| !ctx.native_overrides(x.span, "swift").is_ignored)
+        .map(|(x, enum_value)| {
           format!(
-            "{}case {} = {}",
+            "{}{}case {} = {}",
+            get_swift_doc_comment(ctx, x.span, swift_style::INDENT_SPACE),
             swift_style::INDENT_SPACE,
-            x.to_swift_type(),
-            x.initializer
-              .as_ref()
-              .expect("Unable get initializer value from enum")
-              .get_enum_display_value()
+            x.to_swift_type(ctx),
+            enum_value.to_literal()
           )
         })
         .collect::<Vec<_>>()
         .join("\n");
       let enum_type = self.to_swift_enum_display_type();
-      format!("enum {enum_name}: {enum_type}, CaseIterable {{ \n{enum_cases}\n}}\n")
+      format!(
+        "{doc_comment}{access_level}enum {enum_name}: {enum_type}, Codable, CaseIterable{conformances} {{ \n{enum_cases}\n}}\n"
+      )
     } else {
       let enum_cases: String = self
         .members
         .iter()
-        .map(|x| format!("{}case {}", swift_style::INDENT_SPACE, x.to_swift_type()))
+        .filter(|x| !ctx.native_overrides(x.span, "swift").is_ignored)
+        .map(|x| {
+          format!(
+            "{}{}case {}",
+            get_swift_doc_comment(ctx, x.span, swift_style::INDENT_SPACE),
+            swift_style::INDENT_SPACE,
+            x.to_swift_type(ctx)
+          )
+        })
         .collect::<Vec<_>>()
         .join("\n");
 
-      format!("enum {enum_name}: Int, CaseIterable {{ \n{enum_cases}\n}}\n")
+      format!(
+        "{doc_comment}{access_level}enum {enum_name}: Int, Codable, CaseIterable{conformances} {{ \n{enum_cases}\n}}\n"
+      )
+    }
+  }
+}
+
+impl SwiftType for TSTypeAliasDeclaration<'_> {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
+    let overrides = ctx.native_overrides(self.span, "swift");
+    // integer aliases are emitted as the native `Int` / `Int64`
+    if overrides.is_ignored
+      || overrides.native_type.is_some()
+      || ctx.integer_alias(&self.id.name).is_some()
+    {
+      return "".to_string();
+    }
+
+    if self.get_branded_base_type().is_none()
+      && let TSType::TSIntersectionType(intersection_type) = &self.type_annotation
+    {
+      return to_swift_intersection_type(ctx, self, intersection_type);
+    }
+
+    let alias_name = overrides
+      .name
+      .unwrap_or_else(|| ctx.declared_type_name(&self.id.name, "swift"));
+    if let TSType::TSTupleType(tuple_type) = &self.type_annotation {
+      return format!(
+        "{}{}typealias {alias_name} = {}\n\n",
+        get_swift_doc_comment(ctx, self.span, ""),
+        get_swift_access_level(ctx, self.span),
+        tuple_type.to_swift_type(ctx)
+      );
+    }
+
+    let Some(base_type) = self.get_branded_base_type() else {
+      return "// unknown-type-alias\n\n".to_string();
+    };
+
+    let number_kind = ctx.member_number_kind(self.span, &self.id.name);
+    let raw_type = ctx.with_number_kind(number_kind, || base_type.to_swift_type(ctx));
+    let conformances = overrides
+      .conformances
+      .iter()
+      .map(|conformance| format!(", {conformance}"))
+      .collect::<String>();
+    let doc_comment = get_swift_doc_comment(ctx, self.span, "");
+    let access_level = get_swift_access_level(ctx, self.span);
+
+    // `RawRepresentable` needs an `init(rawValue:)` as visible as the struct
+    let indent = swift_style::INDENT_SPACE;
+    let init = if access_level.is_empty() {
+      "".to_string()
+    } else {
+      format!("{indent}{access_level}init(rawValue: {raw_type}) {{ self.rawValue = rawValue }}\n")
+    };
+
+    format!(
+      "{doc_comment}{access_level}struct {alias_name}: RawRepresentable, Codable, Hashable{conformances} {{\n{indent}{access_level}let rawValue: {raw_type}\n{init}}}\n\n"
+    )
+  }
+}
+
+/// Protocol composition when every part is a protocol, otherwise a single
+/// record with the merged members
+fn to_swift_intersection_type(
+  ctx: &TransformContext,
+  alias_decl: &TSTypeAliasDeclaration,
+  intersection_type: &TSIntersectionType,
+) -> String {
+  let alias_name = alias_decl.id.name.to_string();
+
+  if let Some(interfaces) = get_intersected_interfaces(intersection_type, ctx)
+    && interfaces.iter().all(|x| x.is_swift_protocol_type())
+  {
+    let protocol_names = interfaces
+      .iter()
+      .map(|x| ctx.native_type_name(&x.id.name, "swift"))
+      .collect::<Vec<_>>()
+      .join(" & ");
+    let doc_comment = get_swift_doc_comment(ctx, alias_decl.span, "");
+    let access_level = get_swift_access_level(ctx, alias_decl.span);
+    let alias_name = ctx.native_type_name(&alias_name, "swift");
+
+    return format!("{doc_comment}{access_level}typealias {alias_name} = {protocol_names}\n\n");
+  }
+
+  match get_merged_members(intersection_type, ctx, &alias_name) {
+    Some(members) => to_swift_record_type(ctx, alias_decl.span, &alias_name, &members),
+    None => {
+      ctx.report(format!(
+        "`{alias_name}` intersects types that are not object literals or interfaces of the same file"
+      ));
+      "// unknown-type-alias\n\n".to_string()
     }
   }
 }
 
 impl SwiftType for Statement<'_> {
-  fn to_swift_type(&self) -> String {
+  fn to_swift_type(&self, ctx: &TransformContext) -> String {
     match self {
-      Statement::ExportNamedDeclaration(export_decl) => export_decl.to_swift_type(),
-      Statement::TSInterfaceDeclaration(interface_decl) => interface_decl.to_swift_type(),
-      Statement::TSEnumDeclaration(enum_decl) => enum_decl.to_swift_type(),
+      Statement::ExportNamedDeclaration(export_decl) => export_decl.to_swift_type(ctx),
+      Statement::TSInterfaceDeclaration(interface_decl) => interface_decl.to_swift_type(ctx),
+      Statement::TSEnumDeclaration(enum_decl) => enum_decl.to_swift_type(ctx),
+      Statement::TSTypeAliasDeclaration(alias_decl) => alias_decl.to_swift_type(ctx),
       _ => "// unknown-statement\n\n".to_string(),
     }
   }
@@ -1,3 +1,4 @@
+mod config;
 mod languages;
 mod utils;
 
@@ -5,7 +6,8 @@ use std::{fs, path::Path};
 
 use clap::Parser;
 
-use languages::language_factory::LanguageFactory;
+use config::transform_config::TransformConfig;
+use languages::language_factory::{LanguageFactory, TransformOutput};
 use oxc_allocator::Allocator;
 use oxc_parser::{ParseOptions, Parser as OxcParser};
 use oxc_span::SourceType;
@@ -18,7 +20,8 @@ struct Args {
   /// Input file name
   src: String,
 
-  /// The output file. Target language is inferred from file extension
+  /// The output file. Target language is inferred from file extension.
+  /// For "java", every type is written to its own file in the directory of this file
   #[arg(long)]
   out: String,
 
@@ -31,6 +34,10 @@ struct Args {
   /// comment block for a license or just a fun easter egg.
   #[arg(long)]
   footer: Option<String>,
+
+  /// A JSON config file with target specific options, such as the Java class style
+  #[arg(long)]
+  config: Option<String>,
 }
 
 fn main() {
@@ -43,6 +50,12 @@ fn main() {
 
   let source_type = SourceType::from_path(path).unwrap();
 
+  let config = args
+    .config
+    .as_ref()
+    .map(|config_file| TransformConfig::from_file(config_file).unwrap())
+    .unwrap_or_default();
+
   let allocator = Allocator::default();
   let ret = OxcParser::new(&allocator, &source_text, source_type)
     .with_options(ParseOptions {
@@ -63,22 +76,32 @@ fn main() {
   println!("AST: \n{}", serde_json::to_string_pretty(&program).unwrap());
 
   if ret.errors.is_empty() {
-    let transformed_code = LanguageFactory::transform(&destination_language, &program);
     let out_path = Path::new(&args.out);
-    let banner = parse_banner(&args.banner);
-    let footer = parse_footer(&args.footer);
-
-    let updated_content = format!("{banner}{transformed_code}{footer}");
-
     if let Some(parent) = out_path.parent() {
       fs::create_dir_all(parent).expect("Unable to create parent directory");
     }
 
-    let res = fs::write(out_path, updated_content);
-    match res {
-      Ok(_) => println!("Success"),
-      Err(_) => panic!("Failed to write to file!!"),
+    let transformed_output =
+      LanguageFactory::transform(&destination_language, &program, &config, &args.out)
+        .unwrap_or_else(|err| panic!("{err}"));
+    let banner = parse_banner(&args.banner);
+    let footer = parse_footer(&args.footer);
+
+    let transformed_files = match transformed_output {
+      TransformOutput::Single(transformed_code) => vec![(out_path.to_path_buf(), transformed_code)],
+      TransformOutput::Multiple(files) => files
+        .into_iter()
+        .map(|(file_name, transformed_code)| (out_path.with_file_name(file_name), transformed_code))
+        .collect(),
+    };
+
+    for (file_path, transformed_code) in transformed_files {
+      let updated_content = format!("{banner}{transformed_code}{footer}");
+      if fs::write(file_path, updated_content).is_err() {
+        panic!("Failed to write to file!!");
+      }
     }
+    println!("Success");
   } else {
     for error in ret.errors {
       let error = error.with_source_code(source_text.clone());
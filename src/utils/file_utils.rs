@@ -1,7 +1,12 @@
 use crate::languages::language_factory::LanguageType;
 
 pub fn get_language_from_file_name(file_name: &str) -> Option<LanguageType> {
-  let ext = file_name.split(".").last();
+  // `.schema.json` is the only double extension, a plain `.json` file is not a target
+  let ext = if file_name.ends_with(".schema.json") {
+    Some("schema.json")
+  } else {
+    file_name.split(".").last()
+  };
   match ext {
     Some(val) => LanguageType::try_from(val.to_owned()).ok(),
     None => Option::None,
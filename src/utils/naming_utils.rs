@@ -0,0 +1,66 @@
+/// `userId` / `UserId` / `user-id` -> `user_id`
+pub fn to_snake_case(name: &str) -> String {
+  let mut output = String::new();
+  let mut prev_char: Option<char> = None;
+  let mut chars = name.chars().peekable();
+
+  while let Some(current) = chars.next() {
+    if current == '-' || current == ' ' || current == '_' {
+      if !output.is_empty() && !output.ends_with('_') {
+        output.push('_');
+      }
+    } else if current.is_uppercase() {
+      let after_lower = prev_char.is_some_and(|x| x.is_lowercase() || x.is_ascii_digit());
+      let before_lower = prev_char.is_some_and(|x| x.is_uppercase())
+        && chars.peek().is_some_and(|x| x.is_lowercase());
+      if (after_lower || before_lower) && !output.ends_with('_') {
+        output.push('_');
+      }
+      output.extend(current.to_lowercase());
+    } else {
+      output.push(current);
+    }
+    prev_char = Some(current);
+  }
+
+  output
+}
+
+/// `userId` / `user_id` / `user-id` -> `UserId`, existing capitals such as `USD` are kept
+pub fn to_pascal_case(name: &str) -> String {
+  name
+    .split(['_', '-', ' '])
+    .filter(|word| !word.is_empty())
+    .map(|word| {
+      let mut chars = word.chars();
+      chars
+        .next()
+        .map(|first| format!("{}{}", first.to_uppercase(), chars.as_str()))
+        .unwrap_or_default()
+    })
+    .collect()
+}
+
+/// `user_id` / `UserId` / `USER_ID` -> `userId`
+pub fn to_camel_case(name: &str) -> String {
+  to_snake_case(name)
+    .split('_')
+    .filter(|word| !word.is_empty())
+    .enumerate()
+    .map(|(index, word)| {
+      if index == 0 {
+        return word.to_string();
+      }
+      let mut chars = word.chars();
+      chars
+        .next()
+        .map(|first| format!("{}{}", first.to_uppercase(), chars.as_str()))
+        .unwrap_or_default()
+    })
+    .collect()
+}
+
+/// `userId` / `UserId` / `user-id` -> `USER_ID`
+pub fn to_screaming_snake_case(name: &str) -> String {
+  to_snake_case(name).to_uppercase()
+}